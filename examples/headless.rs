@@ -0,0 +1,54 @@
+//! Drive a board without the TUI by replaying a list of commands.
+//!
+//! ```text
+//! cargo run --example headless                  # built-in script
+//! cargo run --example headless -- commands.json # JSON array of commands
+//! ```
+//!
+//! The resulting board is printed as JSON.
+
+use kanban_tui::{Board, Command, Priority};
+use std::{env, fs, process};
+
+fn main() {
+    let commands = match env::args().nth(1) {
+        Some(path) => {
+            let json = fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Failed to read {}: {}", path, e);
+                process::exit(1);
+            });
+            serde_json::from_str(&json).unwrap_or_else(|e| {
+                eprintln!("Invalid commands in {}: {}", path, e);
+                process::exit(1);
+            })
+        }
+        None => demo_script(),
+    };
+
+    let mut board = Board::new("Headless");
+    if let Err((position, e)) = board.apply_all(commands) {
+        eprintln!("Command {} failed: {}", position + 1, e);
+        process::exit(1);
+    }
+
+    match serde_json::to_string_pretty(&board.to_view()) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Failed to serialize the board: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// A short sprint: two tasks started, one finished
+fn demo_script() -> Vec<Command> {
+    vec![
+        Command::AddTask { column: 0, title: "Write release notes".to_string() },
+        Command::AddTask { column: 0, title: "Fix login crash".to_string() },
+        Command::SetPriority { column: 0, task_id: 2, priority: Priority::High },
+        Command::AddTag { column: 0, task_id: 2, tag: "bug".to_string() },
+        Command::MoveTask { from: 0, to: 1, task_id: 2 },
+        Command::MoveTask { from: 1, to: 2, task_id: 2 },
+        Command::MoveTask { from: 0, to: 1, task_id: 1 },
+    ]
+}
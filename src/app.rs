@@ -1,18 +1,139 @@
 //! Application state management for the Kanban TUI.
 
-use kanban_tui::{storage::Storage, Board};
+use crate::cli;
+use crate::commands;
+use crate::config::{Config, NewTaskTarget, NotStartedStyle};
+use kanban_tui::{storage::Storage, Board, Priority, SortMode, Task, TaskFilter};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
 
 /// Application input mode
 #[derive(Debug, PartialEq)]
 pub enum InputMode {
     Normal,
     Creating,
+    /// One-line quick capture, opened with Ctrl+N from any mode
+    Capturing,
     Editing,
     Viewing,
     EditingDescription,
     AddingTag,
     SelectingBoard,
     CreatingBoard,
+    Confirming,
+    Filtering,
+    TaggingColumn,
+    CompletingTag,
+    SettingDueDate,
+    SettingStartDate,
+    SettingFilteredPriority,
+    EditingBoardDescription,
+    CommandPalette,
+    Help,
+}
+
+/// How long a notification stays on screen
+pub const NOTIFICATION_TTL: Duration = Duration::from_secs(4);
+
+/// Severity of a [`Notification`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationLevel {
+    Info,
+    Error,
+}
+
+/// Transient message shown as a toast in the status bar
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub text: String,
+    pub created: Instant,
+}
+
+/// Action waiting for the user to confirm or resolve it
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfirmAction {
+    DeleteBoard(String),
+    /// The board file changed on disk since it was last loaded or saved
+    ExternalChange,
+    /// Leaving the board with changes that haven't been written yet
+    UnsavedChanges(LeaveAction),
+    /// Merge the given number of duplicate tasks into their first copies
+    MergeDuplicates(usize),
+    /// Permanently remove the given number of archived tasks
+    PurgeArchive(usize),
+    /// Move the given number of open tasks with a tag to the done column
+    CompleteTagged(String, usize),
+    /// Give the current board the columns of the named board, archiving the
+    /// given number of tasks in columns that go away
+    CopyColumns(String, usize),
+}
+
+/// What to do once unsaved changes have been saved or discarded
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeaveAction {
+    Quit,
+    SwitchBoard(String),
+}
+
+impl ConfirmAction {
+    /// Question shown to the user while the action is pending
+    pub fn prompt(&self) -> String {
+        match self {
+            ConfirmAction::DeleteBoard(name) => {
+                format!("Delete board '{}' and all its tasks? (y/n)", name)
+            }
+            ConfirmAction::ExternalChange => {
+                "File changed externally — (o)verwrite / (r)eload / (c)ancel".to_string()
+            }
+            ConfirmAction::UnsavedChanges(_) => {
+                "Unsaved changes — (s)ave / (d)iscard / (c)ancel".to_string()
+            }
+            ConfirmAction::MergeDuplicates(count) => {
+                format!("Merge {} duplicate task(s) into their first copies? (y/n)", count)
+            }
+            ConfirmAction::PurgeArchive(count) => {
+                format!("Permanently delete {} archived task(s)? (y/n)", count)
+            }
+            ConfirmAction::CompleteTagged(tag, count) => {
+                format!("Move {} task(s) tagged '{}' to done? (y/n)", count, tag)
+            }
+            ConfirmAction::CopyColumns(name, 0) => {
+                format!("Use the columns of '{}' on this board? (y/n)", name)
+            }
+            ConfirmAction::CopyColumns(name, count) => {
+                format!("Use the columns of '{}' on this board, archiving {} task(s)? (y/n)", name, count)
+            }
+        }
+    }
+
+    /// Whether the action destroys data (and can be skipped via `confirm_destructive`)
+    fn is_destructive(&self) -> bool {
+        match self {
+            ConfirmAction::DeleteBoard(_)
+            | ConfirmAction::MergeDuplicates(_)
+            | ConfirmAction::PurgeArchive(_) => true,
+            ConfirmAction::ExternalChange
+            | ConfirmAction::UnsavedChanges(_)
+            | ConfirmAction::CompleteTagged(..)
+            | ConfirmAction::CopyColumns(..) => false,
+        }
+    }
+
+    /// Mode to return to once the action is confirmed or cancelled
+    fn return_mode(&self) -> InputMode {
+        match self {
+            ConfirmAction::DeleteBoard(_) => InputMode::SelectingBoard,
+            ConfirmAction::ExternalChange
+            | ConfirmAction::UnsavedChanges(_)
+            | ConfirmAction::MergeDuplicates(_)
+            | ConfirmAction::PurgeArchive(_)
+            | ConfirmAction::CompleteTagged(..)
+            | ConfirmAction::CopyColumns(..) => InputMode::Normal,
+        }
+    }
 }
 
 /// Application state
@@ -26,32 +147,106 @@ pub struct App {
     pub storage: Storage,
     pub current_board_name: String,
     pub available_boards: Vec<String>,
+    pub archived_boards: Vec<String>,
+    /// The board selector lists archived boards instead of active ones
+    pub show_archived: bool,
     pub selected_board_index: Option<usize>,
+    pub config: Config,
+    pub undo_stack: VecDeque<Board>,
+    pub status_message: Option<String>,
+    pub last_save: Option<(Instant, Result<(), String>)>,
+    pub pending_confirmation: Option<ConfirmAction>,
+    pub filter: TaskFilter,
+    /// Modification time of the board file as of our last load or save
+    pub board_mtime: Option<SystemTime>,
+    /// Position while recalling title history (0 = most recent) and the draft it replaced
+    pub history_recall: Option<(usize, String)>,
+    /// Task yanked with Ctrl+Y, kept across board switches until replaced
+    pub clipboard: Option<Task>,
+    /// The board has changes that haven't been written to disk
+    pub dirty: bool,
+    /// Set once the user has chosen to quit
+    pub should_quit: bool,
+    /// Board that was active before the current one, for quick toggling
+    pub previous_board_name: Option<String>,
+    /// Highlighted entry among the commands matching the palette input
+    pub command_selection: usize,
+    /// Lines the help screen is scrolled down by
+    pub help_scroll: u16,
+    /// Toasts from recent actions, oldest first; expired ones are dropped each frame
+    pub notifications: Vec<Notification>,
+    /// Mode and input to go back to once a quick capture is done
+    pub capture_return: Option<(InputMode, String)>,
 }
 
 impl App {
-    pub fn new() -> Self {
-        let storage = Storage::new().expect("Failed to initialize storage");
-
-        // Get active board name and load it
-        let current_board_name = storage.get_active_board_name()
-            .unwrap_or_else(|_| "default".to_string());
+    /// Create the app, optionally opening `initial_board` instead of the stored active board.
+    ///
+    /// The board is created if it doesn't exist yet. Without an explicit
+    /// board, the app may start in the board selector (see `starts_in_selector`).
+    pub fn new(initial_board: Option<&str>, config: Config) -> Result<Self, String> {
+        let storage = Storage::new().map_err(|e| format!("Failed to initialize storage: {}", e))?;
+        if let Some(requested) = initial_board {
+            let name = cli::resolve_board_name(Some(requested), None)?;
+            storage
+                .set_active_board_name(&name)
+                .map_err(|e| format!("Failed to open board '{}': {}", name, e))?;
+        }
+        let board_count = storage.board_count().unwrap_or(0);
+        let mut app = Self::with_storage(storage, config);
+        if starts_in_selector(&app.config, board_count, initial_board.is_some()) {
+            app.start_board_selection();
+        }
+        Ok(app)
+    }
 
-        let board = storage
-            .load_board(&current_board_name)
-            .ok()
-            .flatten()
-            .unwrap_or_else(|| Board::new(&current_board_name));
+    /// Create the app on a throwaway copy of `board` kept in a temporary
+    /// directory, leaving the real boards directory untouched.
+    ///
+    /// The caller should remove [`Storage::storage_location`] when done.
+    pub fn ephemeral(board: Board, config: Config) -> Result<Self, String> {
+        // Unique per session, so concurrent sessions never share or delete each other's files
+        static SESSIONS: AtomicUsize = AtomicUsize::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let dir = std::env::temp_dir().join(format!(
+            "kanban-tui-ephemeral-{}-{}-{}",
+            std::process::id(),
+            nanos,
+            SESSIONS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let storage = Storage::with_path(dir);
+        let name = match board.name.trim() {
+            "" => "stdin".to_string(),
+            name => name.to_string(),
+        };
+        storage
+            .save_board(&name, &board)
+            .and_then(|()| storage.set_active_board_name(&name))
+            .map_err(|e| format!("Failed to set up temporary board: {}", e))?;
+        Ok(Self::with_storage(storage, config))
+    }
 
-        // If board doesn't exist yet, save it
-        if !storage.board_exists(&current_board_name) {
-            let _ = storage.save_board(&current_board_name, &board);
-        }
+    /// Create the app on top of an explicit storage and configuration
+    pub fn with_storage(storage: Storage, config: Config) -> Self {
+        // Load the active board; if it can't be read, start empty without overwriting it
+        let (current_board_name, board) = storage.load_active_board().unwrap_or_else(|_| {
+            let name = storage
+                .get_active_board_name()
+                .unwrap_or_else(|_| "default".to_string());
+            let board = Board::new(&name);
+            (name, board)
+        });
 
         // Load available boards
         let available_boards = storage.list_boards()
             .unwrap_or_else(|_| vec![current_board_name.clone()]);
 
+        let archived_boards = storage.list_archived_boards().unwrap_or_default();
+
+        let board_mtime = storage.board_mtime(&current_board_name).ok().flatten();
+
         Self {
             board,
             selected_column: 0,
@@ -62,21 +257,210 @@ impl App {
             storage,
             current_board_name,
             available_boards,
+            archived_boards,
+            show_archived: false,
             selected_board_index: None,
+            config,
+            undo_stack: VecDeque::new(),
+            status_message: None,
+            last_save: None,
+            pending_confirmation: None,
+            filter: TaskFilter::default(),
+            history_recall: None,
+            board_mtime,
+            clipboard: None,
+            dirty: false,
+            should_quit: false,
+            previous_board_name: None,
+            command_selection: 0,
+            help_scroll: 0,
+            notifications: Vec::new(),
+            capture_return: None,
+        }
+    }
+
+    /// Record a change to the board, saving it straight away when auto-save is on
+    pub fn save(&mut self) {
+        self.dirty = true;
+        if self.config.auto_save {
+            self.save_now();
+        }
+    }
+
+    /// Save the board to persistent storage, recording the outcome for the status bar
+    ///
+    /// If the file was modified by another program since we last loaded or
+    /// saved it, nothing is written and the user is asked how to resolve it.
+    pub fn save_now(&mut self) {
+        if self.has_external_change() {
+            self.request_confirmation(ConfirmAction::ExternalChange);
+            return;
+        }
+        self.write_board();
+    }
+
+    fn write_board(&mut self) {
+        if let Err(e) = self.try_write_board() {
+            self.notify(NotificationLevel::Error, format!("Save failed: {}", e));
+        }
+    }
+
+    /// Write the board without reporting a failure, leaving that to the caller
+    fn try_write_board(&mut self) -> Result<(), String> {
+        // Columns over their auto-archive limit are trimmed as part of saving
+        let auto_archived = self.board.auto_archive();
+        if auto_archived > 0 {
+            self.clamp_selection();
+            self.notify(NotificationLevel::Info, format!("Auto-archived {} task(s)", auto_archived));
+        }
+
+        let result = self
+            .storage
+            .save_board(&self.current_board_name, &self.board)
+            .map_err(|e| e.to_string());
+        self.record_board_mtime();
+        if result.is_ok() {
+            self.dirty = false;
+        }
+        self.last_save = Some((Instant::now(), result.clone()));
+        result
+    }
+
+    // === Notifications ===
+
+    pub fn notify(&mut self, level: NotificationLevel, text: impl Into<String>) {
+        self.notifications.push(Notification {
+            level,
+            text: text.into(),
+            created: Instant::now(),
+        });
+    }
+
+    /// Pass through a successful result, turning a failure into an error notification
+    fn report<T, E: Display>(&mut self, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.notify(NotificationLevel::Error, e.to_string());
+                None
+            }
+        }
+    }
+
+    /// Drop notifications that have been shown for longer than [`NOTIFICATION_TTL`]
+    pub fn expire_notifications(&mut self) {
+        self.expire_notifications_at(Instant::now());
+    }
+
+    /// Drop notifications that had been shown for longer than [`NOTIFICATION_TTL`] at `now`
+    pub fn expire_notifications_at(&mut self, now: Instant) {
+        self.notifications
+            .retain(|notification| now.saturating_duration_since(notification.created) < NOTIFICATION_TTL);
+    }
+
+    /// The newest notification, shown as the toast
+    pub fn current_notification(&self) -> Option<&Notification> {
+        self.notifications.last()
+    }
+
+    fn record_board_mtime(&mut self) {
+        self.board_mtime = self.storage.board_mtime(&self.current_board_name).ok().flatten();
+    }
+
+    /// Returns true if the board file on disk changed since our last load or save
+    pub fn has_external_change(&self) -> bool {
+        match self.storage.board_mtime(&self.current_board_name) {
+            Ok(mtime) => mtime != self.board_mtime,
+            Err(_) => false,
+        }
+    }
+
+    /// Replace the in-memory board with the copy on disk, keeping local edits undoable
+    fn reload_board(&mut self) {
+        match self.storage.load_board(&self.current_board_name) {
+            Ok(Some(board)) => {
+                let selected_id = self.selected_task_id();
+                let local = std::mem::replace(&mut self.board, board);
+                self.push_undo(local);
+                self.record_board_mtime();
+                self.dirty = false;
+                self.clamp_selection();
+                self.reselect_task(selected_id);
+                self.notify(NotificationLevel::Info, "Reloaded board from disk");
+            }
+            Ok(None) => self.notify(NotificationLevel::Error, "Board file no longer exists"),
+            Err(e) => self.notify(NotificationLevel::Error, format!("Reload failed: {}", e)),
+        }
+    }
+
+    /// Resolve a pending external-change conflict by loading the file on disk
+    pub fn reload_after_external_change(&mut self) {
+        self.pending_confirmation = None;
+        self.input_mode = InputMode::Normal;
+        self.reload_board();
+    }
+
+    // === Undo ===
+
+    /// Record a board snapshot taken before a mutation, dropping the oldest
+    /// snapshot once `undo_limit` is reached
+    fn push_undo(&mut self, snapshot: Board) {
+        if self.config.undo_limit == 0 {
+            return;
+        }
+
+        self.undo_stack.push_back(snapshot);
+        while self.undo_stack.len() > self.config.undo_limit {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    /// Snapshot the current board before mutating it
+    fn checkpoint(&mut self) {
+        self.push_undo(self.board.clone());
+    }
+
+    pub fn undo(&mut self) {
+        if self.config.undo_limit == 0 {
+            self.status_message = Some("undo disabled".to_string());
+            return;
+        }
+
+        match self.undo_stack.pop_back() {
+            Some(snapshot) => {
+                let selected_id = self.selected_task_id();
+                self.board = snapshot;
+                self.clamp_selection();
+                self.reselect_task(selected_id);
+                self.save();
+            }
+            None => self.status_message = Some("Nothing to undo".to_string()),
         }
     }
 
-    /// Save the board to persistent storage
-    pub fn save(&self) {
-        if let Err(e) = self.storage.save_board(&self.current_board_name, &self.board) {
-            eprintln!("Failed to save board: {}", e);
+    /// Keep the column and task selection within the bounds of the current board
+    fn clamp_selection(&mut self) {
+        if self.selected_column >= self.board.columns.len() {
+            self.selected_column = self.board.columns.len().saturating_sub(1);
         }
+
+        let task_count = self
+            .board
+            .columns
+            .get(self.selected_column)
+            .map_or(0, |column| column.tasks.len());
+        self.selected_task_index = match self.selected_task_index {
+            _ if task_count == 0 => None,
+            Some(idx) if idx >= task_count => Some(task_count - 1),
+            other => other,
+        };
     }
 
     // === Board Management ===
 
     pub fn start_board_selection(&mut self) {
         self.input_mode = InputMode::SelectingBoard;
+        self.show_archived = false;
         // Select current board in list
         self.selected_board_index = self.available_boards
             .iter()
@@ -86,21 +470,33 @@ impl App {
     pub fn cancel_board_selection(&mut self) {
         self.input_mode = InputMode::Normal;
         self.selected_board_index = None;
+        self.show_archived = false;
+    }
+
+    /// Boards listed in the board selector: active ones, or archived ones when revealed
+    pub fn selector_boards(&self) -> &[String] {
+        if self.show_archived {
+            &self.archived_boards
+        } else {
+            &self.available_boards
+        }
     }
 
     pub fn next_board_in_list(&mut self) {
-        if self.available_boards.is_empty() {
+        let count = self.selector_boards().len();
+        if count == 0 {
             return;
         }
 
         self.selected_board_index = Some(match self.selected_board_index {
-            Some(idx) => (idx + 1) % self.available_boards.len(),
+            Some(idx) => (idx + 1) % count,
             None => 0,
         });
     }
 
     pub fn previous_board_in_list(&mut self) {
-        if self.available_boards.is_empty() {
+        let count = self.selector_boards().len();
+        if count == 0 {
             return;
         }
 
@@ -109,35 +505,113 @@ impl App {
                 if idx > 0 {
                     idx - 1
                 } else {
-                    self.available_boards.len() - 1
+                    count - 1
                 }
             }
             None => 0,
         });
     }
 
+    /// Open the selected board; an archived board is restored on opening
     pub fn switch_to_selected_board(&mut self) {
-        if let Some(idx) = self.selected_board_index {
-            if idx < self.available_boards.len() {
-                let board_name = self.available_boards[idx].clone();
-                self.input_buffer = board_name;
-                self.switch_board();
-            }
+        let selected = self
+            .selected_board_index
+            .and_then(|idx| self.selector_boards().get(idx))
+            .cloned();
+        if let Some(board_name) = selected {
+            self.input_buffer = board_name;
+            let result = self.switch_board();
+            self.report(result);
         }
         self.input_mode = InputMode::Normal;
         self.selected_board_index = None;
+        self.show_archived = false;
     }
 
-    fn switch_board(&mut self) {
-        let board_name = self.input_buffer.trim().to_string();
+    /// Switch the board selector between active and archived boards
+    pub fn toggle_archived_view(&mut self) {
+        self.show_archived = !self.show_archived;
+        self.selected_board_index = (!self.selector_boards().is_empty()).then_some(0);
+    }
+
+    /// Archive the selected board, or restore it when viewing archived boards.
+    ///
+    /// The open board can't be archived; switch away from it first.
+    pub fn toggle_selected_board_archived(&mut self) {
+        let Some(name) = self
+            .selected_board_index
+            .and_then(|idx| self.selector_boards().get(idx))
+            .cloned()
+        else {
+            return;
+        };
 
-        if board_name.is_empty() {
+        let result = if self.show_archived {
+            self.storage.unarchive_board(&name)
+        } else if name == self.current_board_name {
+            self.status_message = Some("Can't archive the open board".to_string());
+            return;
+        } else {
+            self.storage.archive_board(&name)
+        };
+        if self.report(result).is_none() {
             return;
         }
 
-        // Save current board before switching
-        self.save();
+        self.refresh_board_lists();
+        let count = self.selector_boards().len();
+        self.selected_board_index = self
+            .selected_board_index
+            .map(|idx| idx.min(count.saturating_sub(1)))
+            .filter(|_| count > 0);
+        let verb = if self.show_archived { "Restored" } else { "Archived" };
+        self.status_message = Some(format!("{} board '{}'", verb, name));
+    }
 
+    /// Reload the active and archived board names from storage
+    fn refresh_board_lists(&mut self) {
+        let result = self.storage.list_boards();
+        if let Some(boards) = self.report(result) {
+            self.available_boards = boards;
+        }
+        self.archived_boards = self.storage.list_archived_boards().unwrap_or_default();
+    }
+
+    /// Switch to the board named in the input buffer, saving the current one first.
+    ///
+    /// If that save fails the switch is abandoned and the current board stays
+    /// open, so its changes aren't lost. Unsaved changes and external edits
+    /// are resolved through a confirmation before switching. Picking the
+    /// board that's already open does nothing, keeping the selection.
+    fn switch_board(&mut self) -> Result<(), String> {
+        let board_name = self.input_buffer.trim().to_string();
+
+        if board_name.is_empty() || board_name == self.current_board_name {
+            return Ok(());
+        }
+
+        // Unsaved changes must be saved or discarded first
+        if self.dirty {
+            self.request_confirmation(ConfirmAction::UnsavedChanges(LeaveAction::SwitchBoard(board_name)));
+            return Ok(());
+        }
+
+        // Save current board before switching; an external change must be resolved first
+        if self.config.auto_save {
+            if self.has_external_change() {
+                self.request_confirmation(ConfirmAction::ExternalChange);
+                return Ok(());
+            }
+            self.try_write_board()
+                .map_err(|e| format!("Staying on '{}': {}", self.current_board_name, e))?;
+        }
+
+        self.open_board(board_name);
+        Ok(())
+    }
+
+    /// Load the named board (creating it if needed) and make it the active one
+    fn open_board(&mut self, board_name: String) {
         // Load or create new board
         let new_board = self.storage
             .load_board(&board_name)
@@ -146,21 +620,76 @@ impl App {
             .unwrap_or_else(|| Board::new(&board_name));
 
         self.board = new_board;
-        self.current_board_name = board_name.clone();
+        let previous = std::mem::replace(&mut self.current_board_name, board_name);
+        if previous != self.current_board_name {
+            self.previous_board_name = Some(previous);
+        }
+        self.undo_stack.clear();
+        self.dirty = false;
 
         // Save the new board and update metadata
-        let _ = self.storage.save_board(&board_name, &self.board);
-        let _ = self.storage.set_active_board_name(&board_name);
+        let result = self.storage.save_board(&self.current_board_name, &self.board);
+        self.report(result);
+        let result = self.storage.set_active_board_name(&self.current_board_name);
+        self.report(result);
+        self.record_board_mtime();
 
         // Refresh available boards list
         self.available_boards = self.storage.list_boards()
-            .unwrap_or_else(|_| vec![board_name]);
+            .unwrap_or_else(|_| vec![self.current_board_name.clone()]);
+        self.archived_boards = self.storage.list_archived_boards().unwrap_or_default();
 
         // Reset selections
         self.selected_column = 0;
         self.selected_task_index = None;
     }
 
+    /// Switch back to the previously active board, so pressing again toggles
+    /// between the two. Opens the board selector if there is no previous board
+    /// or it has since been deleted.
+    pub fn toggle_previous_board(&mut self) {
+        let previous = self
+            .previous_board_name
+            .as_ref()
+            .filter(|name| **name != self.current_board_name && self.storage.board_exists(name))
+            .cloned();
+
+        match previous {
+            Some(name) => {
+                self.input_buffer = name;
+                let result = self.switch_board();
+            self.report(result);
+                self.input_buffer.clear();
+            }
+            None => {
+                if self.previous_board_name.take().is_some() {
+                    self.status_message = Some("Previous board no longer exists".to_string());
+                }
+                self.start_board_selection();
+            }
+        }
+    }
+
+    pub fn start_editing_board_description(&mut self) {
+        self.input_mode = InputMode::EditingBoardDescription;
+        self.input_buffer = self.board.description.clone();
+    }
+
+    /// Replace the board description with the input buffer; an empty buffer clears it
+    pub fn save_board_description(&mut self) {
+        if self.input_buffer.trim() != self.board.description {
+            self.checkpoint();
+            self.board.set_description(self.input_buffer.as_str());
+            self.save();
+        }
+        self.cancel_editing_board_description();
+    }
+
+    pub fn cancel_editing_board_description(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+
     pub fn start_creating_board(&mut self) {
         self.input_mode = InputMode::CreatingBoard;
         self.input_buffer.clear();
@@ -169,7 +698,8 @@ impl App {
     pub fn create_new_board(&mut self) {
         if !self.input_buffer.is_empty() {
             // Create and switch to new board (board_name is in input_buffer)
-            self.switch_board();
+            let result = self.switch_board();
+            self.report(result);
         }
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
@@ -180,368 +710,2735 @@ impl App {
         self.input_buffer.clear();
     }
 
+    /// Ask for confirmation before deleting the board selected in the board selector
     pub fn delete_selected_board(&mut self) {
+        // Archived boards have to be restored before they can be deleted
+        if self.show_archived {
+            return;
+        }
         if let Some(idx) = self.selected_board_index {
             if idx < self.available_boards.len() {
-                let board_to_delete = self.available_boards[idx].clone();
-
                 // Don't delete if it's the only board
                 if self.available_boards.len() <= 1 {
                     return;
                 }
 
-                // Delete the board
-                if let Ok(()) = self.storage.delete_board(&board_to_delete) {
-                    // Refresh board list
-                    self.available_boards = self.storage.list_boards()
-                        .unwrap_or_else(|_| vec!["default".to_string()]);
-
-                    // If we deleted the current board, switch to first available
-                    if board_to_delete == self.current_board_name {
-                        if let Some(first_board) = self.available_boards.first() {
-                            let new_board = self.storage
-                                .load_board(first_board)
-                                .ok()
-                                .flatten()
-                                .unwrap_or_else(|| Board::new(first_board));
-
-                            self.board = new_board;
-                            self.current_board_name = first_board.clone();
-                            let _ = self.storage.set_active_board_name(first_board);
-                        }
-                    }
-
-                    // Adjust selection
-                    if idx >= self.available_boards.len() {
-                        self.selected_board_index = Some(self.available_boards.len().saturating_sub(1));
-                    }
-                }
+                let board_to_delete = self.available_boards[idx].clone();
+                self.request_confirmation(ConfirmAction::DeleteBoard(board_to_delete));
             }
         }
     }
 
-    // === Column Navigation ===
+    fn delete_board(&mut self, board_to_delete: &str) {
+        // Don't delete if it's the only board
+        if self.available_boards.len() <= 1 {
+            return;
+        }
 
-    pub fn next_column(&mut self) {
-        self.selected_column = (self.selected_column + 1) % self.board.columns.len();
-        self.update_task_selection();
-    }
+        // Delete the board
+        let result = self.storage.delete_board(board_to_delete);
+        if self.report(result).is_some() {
+            // Refresh board list
+            self.available_boards = self.storage.list_boards()
+                .unwrap_or_else(|_| vec!["default".to_string()]);
+
+            // If we deleted the current board, switch to first available
+            if board_to_delete == self.current_board_name {
+                if let Some(first_board) = self.available_boards.first() {
+                    let new_board = self.storage
+                        .load_board(first_board)
+                        .ok()
+                        .flatten()
+                        .unwrap_or_else(|| Board::new(first_board));
+
+                    self.board = new_board;
+                    self.current_board_name = first_board.clone();
+                    self.undo_stack.clear();
+                    self.dirty = false;
+                    let result = self.storage.set_active_board_name(first_board);
+                    self.report(result);
+                    self.record_board_mtime();
+
+                    self.selected_column = 0;
+                    self.selected_task_index = None;
+                }
+            }
 
-    pub fn previous_column(&mut self) {
-        if self.selected_column > 0 {
-            self.selected_column -= 1;
-        } else {
-            self.selected_column = self.board.columns.len() - 1;
+            // Adjust selection
+            if let Some(idx) = self.selected_board_index {
+                if idx >= self.available_boards.len() {
+                    self.selected_board_index = Some(self.available_boards.len().saturating_sub(1));
+                }
+            }
         }
-        self.update_task_selection();
     }
 
-    pub fn update_task_selection(&mut self) {
-        // Auto-select first task if column has tasks, otherwise clear selection
-        let task_count = self.board.columns[self.selected_column].tasks.len();
-        self.selected_task_index = if task_count > 0 { Some(0) } else { None };
-    }
+    /// Ask to give the current board the columns of the board selected in the selector
+    pub fn request_copy_columns(&mut self) {
+        let Some(name) = self
+            .selected_board_index
+            .and_then(|idx| self.selector_boards().get(idx))
+            .cloned()
+        else {
+            return;
+        };
+        if name == self.current_board_name {
+            self.status_message = Some("Pick another board to copy its columns".to_string());
+            return;
+        }
 
-    // === Task Navigation ===
+        let result = self.storage.load_board(&name);
+        let Some(source) = self.report(result).flatten() else {
+            return;
+        };
+        let names = source.column_names();
+        let archived = self
+            .board
+            .columns
+            .iter()
+            .filter(|column| !names.contains(&column.name))
+            .map(|column| column.tasks.len())
+            .sum();
+        self.request_confirmation(ConfirmAction::CopyColumns(name, archived));
+    }
 
-    pub fn next_task(&mut self) {
-        let task_count = self.board.columns[self.selected_column].tasks.len();
-        if task_count == 0 {
+    fn copy_columns_from(&mut self, name: &str) {
+        let result = self.storage.load_board(name);
+        let Some(source) = self.report(result).flatten() else {
             return;
+        };
+
+        self.checkpoint();
+        let result = self.board.set_columns_from(&source.column_names());
+        if let Some(archived) = self.report(result) {
+            self.status_message = Some(match archived {
+                0 => format!("Copied the columns of '{}'", name),
+                n => format!("Copied the columns of '{}' and archived {} task(s)", name, n),
+            });
         }
-
-        self.selected_task_index = Some(match self.selected_task_index {
-            Some(idx) => (idx + 1) % task_count,
-            None => 0,
-        });
+        self.clamp_selection();
+        self.save();
     }
 
-    pub fn previous_task(&mut self) {
-        let task_count = self.board.columns[self.selected_column].tasks.len();
-        if task_count == 0 {
+    // === Confirmation ===
+
+    /// Ask the user to confirm an action, or run it straight away if it's
+    /// destructive and `confirm_destructive` is turned off
+    fn request_confirmation(&mut self, action: ConfirmAction) {
+        if action.is_destructive() && !self.config.confirm_destructive {
+            self.input_mode = action.return_mode();
+            self.perform(action);
             return;
         }
 
-        self.selected_task_index = Some(match self.selected_task_index {
-            Some(idx) => {
-                if idx > 0 {
-                    idx - 1
-                } else {
-                    task_count - 1
-                }
-            }
-            None => 0,
-        });
+        self.pending_confirmation = Some(action);
+        self.input_mode = InputMode::Confirming;
     }
 
-    // === Task Management ===
-
-    pub fn delete_selected_task(&mut self) {
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
+    /// Make sure a confirmation requested mid-action (such as a save conflict)
+    /// isn't hidden by the action switching modes afterwards
+    pub fn surface_pending_confirmation(&mut self) {
+        if self.pending_confirmation.is_some() {
+            self.input_mode = InputMode::Confirming;
+        }
+    }
 
-            // Get task ID before deletion
-            if task_idx < column.tasks.len() {
-                let task_id = column.tasks[task_idx].id;
+    pub fn confirm(&mut self) {
+        let Some(action) = self.pending_confirmation.take() else {
+            self.input_mode = InputMode::Normal;
+            return;
+        };
 
-                // Remove the task
-                self.board.columns[self.selected_column].remove_task(task_id);
+        self.input_mode = action.return_mode();
+        self.perform(action);
+    }
 
-                // Adjust selection after deletion
-                let new_task_count = self.board.columns[self.selected_column].tasks.len();
-                if new_task_count == 0 {
-                    self.selected_task_index = None;
-                } else if task_idx >= new_task_count {
-                    // If we deleted the last task, select the new last task
-                    self.selected_task_index = Some(new_task_count - 1);
+    fn perform(&mut self, action: ConfirmAction) {
+        match action {
+            ConfirmAction::DeleteBoard(name) => self.delete_board(&name),
+            ConfirmAction::ExternalChange => self.write_board(),
+            ConfirmAction::MergeDuplicates(_) => self.merge_duplicates(),
+            ConfirmAction::PurgeArchive(_) => self.purge_archive(),
+            ConfirmAction::CompleteTagged(tag, _) => self.complete_tagged(&tag),
+            ConfirmAction::CopyColumns(name, _) => self.copy_columns_from(&name),
+            ConfirmAction::UnsavedChanges(leave) => {
+                self.save_now();
+                if !self.dirty && self.pending_confirmation.is_none() {
+                    self.leave(leave);
                 }
-                // Otherwise keep the same index (which now points to the next task)
-
-                // Save after deletion
-                self.save();
             }
         }
     }
 
-    pub fn move_task_left(&mut self) {
-        // Can't move left from first column
-        if self.selected_column == 0 {
-            return;
+    /// Drop the unsaved changes and carry on with quitting or switching boards
+    pub fn discard_unsaved_changes(&mut self) {
+        if let Some(ConfirmAction::UnsavedChanges(leave)) = self.pending_confirmation.take() {
+            self.input_mode = InputMode::Normal;
+            self.dirty = false;
+            self.leave(leave);
         }
+    }
 
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
-
-            if task_idx < column.tasks.len() {
-                let task_id = column.tasks[task_idx].id;
-                let from_column = self.selected_column;
-                let to_column = self.selected_column - 1;
-
-                // Move the task
-                if self.board.move_task(from_column, to_column, task_id).is_ok() {
-                    // Update selected column
-                    self.selected_column = to_column;
-
-                    // Find the moved task in the new column and select it
-                    let new_task_index = self.board.columns[to_column]
-                        .tasks
-                        .iter()
-                        .position(|t| t.id == task_id);
-                    self.selected_task_index = new_task_index;
-
-                    // Save after move
-                    self.save();
-                }
-            }
+    fn leave(&mut self, leave: LeaveAction) {
+        match leave {
+            LeaveAction::Quit => self.should_quit = true,
+            LeaveAction::SwitchBoard(name) => self.open_board(name),
         }
     }
 
-    pub fn move_task_right(&mut self) {
-        // Can't move right from last column
-        if self.selected_column >= self.board.columns.len() - 1 {
+    /// Ask to merge tasks with the same title, if there are any
+    pub fn request_merge_duplicates(&mut self) {
+        let count: usize = self.board.find_duplicates().iter().map(|ids| ids.len() - 1).sum();
+        if count == 0 {
+            self.status_message = Some("No duplicate tasks".to_string());
             return;
         }
+        self.request_confirmation(ConfirmAction::MergeDuplicates(count));
+    }
 
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
+    fn merge_duplicates(&mut self) {
+        self.checkpoint();
+        let removed = self.board.dedupe();
+        self.clamp_selection();
+        self.status_message = Some(format!("Merged {} duplicate task(s)", removed));
+        self.save();
+    }
 
-            if task_idx < column.tasks.len() {
-                let task_id = column.tasks[task_idx].id;
-                let from_column = self.selected_column;
-                let to_column = self.selected_column + 1;
+    /// Ask to permanently delete every archived task
+    pub fn request_purge_archive(&mut self) {
+        let count = self.board.archived_tasks().len();
+        if count == 0 {
+            self.status_message = Some("The archive is empty".to_string());
+            return;
+        }
+        self.request_confirmation(ConfirmAction::PurgeArchive(count));
+    }
 
-                // Move the task
-                if self.board.move_task(from_column, to_column, task_id).is_ok() {
-                    // Update selected column
-                    self.selected_column = to_column;
+    fn purge_archive(&mut self) {
+        self.checkpoint();
+        let purged = self.board.purge_archived();
+        self.status_message = Some(format!("Purged {} archived task(s)", purged));
+        self.save();
+    }
 
-                    // Find the moved task in the new column and select it
-                    let new_task_index = self.board.columns[to_column]
-                        .tasks
-                        .iter()
-                        .position(|t| t.id == task_id);
-                    self.selected_task_index = new_task_index;
+    /// Quit, asking first if there are unsaved changes
+    pub fn request_quit(&mut self) {
+        if self.dirty {
+            self.request_confirmation(ConfirmAction::UnsavedChanges(LeaveAction::Quit));
+        } else {
+            self.should_quit = true;
+        }
+    }
 
-                    // Save after move
-                    self.save();
-                }
-            }
+    pub fn cancel_confirmation(&mut self) {
+        let action = self.pending_confirmation.take();
+        if action == Some(ConfirmAction::ExternalChange) {
+            self.status_message = Some("Changes not saved".to_string());
         }
+        self.input_mode = match action {
+            Some(action) => action.return_mode(),
+            None => InputMode::Normal,
+        };
     }
 
-    // === Task Creation/Editing ===
+    // === Help ===
 
-    pub fn start_creating(&mut self) {
-        self.input_mode = InputMode::Creating;
-        self.input_buffer.clear();
+    pub fn show_help(&mut self) {
+        self.input_mode = InputMode::Help;
+        self.help_scroll = 0;
     }
 
-    pub fn create_task(&mut self) {
-        if !self.input_buffer.is_empty() {
-            let _ = self.board.add_task(self.selected_column, &self.input_buffer);
-            self.input_buffer.clear();
-
-            // Select the newly created task (last one in the column)
-            let task_count = self.board.columns[self.selected_column].tasks.len();
-            if task_count > 0 {
-                self.selected_task_index = Some(task_count - 1);
-            }
+    /// Scroll the help screen by `lines` (negative scrolls up).
+    ///
+    /// Bounded by the command list, the only part long enough to scroll;
+    /// rendering stops earlier when the rest already fits.
+    pub fn scroll_help(&mut self, lines: i32) {
+        let max = commands::COMMANDS.len() as i32;
+        self.help_scroll = (i32::from(self.help_scroll) + lines).clamp(0, max) as u16;
+    }
 
-            // Save after creation
-            self.save();
-        }
+    pub fn close_help(&mut self) {
         self.input_mode = InputMode::Normal;
     }
 
-    pub fn cancel_creating(&mut self) {
+    // === Command Palette ===
+
+    pub fn start_command_palette(&mut self) {
+        self.input_mode = InputMode::CommandPalette;
+        self.input_buffer.clear();
+        self.command_selection = 0;
+    }
+
+    pub fn cancel_command_palette(&mut self) {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
+        self.command_selection = 0;
     }
 
-    pub fn start_editing(&mut self) {
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
-            if task_idx < column.tasks.len() {
-                let task = &column.tasks[task_idx];
-                self.editing_task_id = Some(task.id);
-                self.input_buffer = task.title.clone();
-                self.input_mode = InputMode::Editing;
-            }
+    pub fn next_command(&mut self) {
+        let count = commands::matching(&self.input_buffer).len();
+        if count > 0 {
+            self.command_selection = (self.command_selection + 1) % count;
         }
     }
 
-    pub fn save_edit(&mut self) {
-        if let Some(task_id) = self.editing_task_id {
-            if !self.input_buffer.is_empty() {
-                let _ = self.board.update_task_title(
-                    self.selected_column,
-                    task_id,
-                    &self.input_buffer,
-                );
-
-                // Save after editing
-                self.save();
-            }
+    pub fn previous_command(&mut self) {
+        let count = commands::matching(&self.input_buffer).len();
+        if count > 0 {
+            self.command_selection = (self.command_selection + count - 1) % count;
         }
+    }
 
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-        self.editing_task_id = None;
+    /// Close the palette and run the highlighted command.
+    ///
+    /// With no match the palette stays open so the input can be corrected.
+    pub fn run_selected_command(&mut self) {
+        let matches = commands::matching(&self.input_buffer);
+        let Some(command) = matches.get(self.command_selection).or(matches.first()) else {
+            self.status_message = Some(format!("No command matches '{}'", self.input_buffer.trim()));
+            return;
+        };
+        self.cancel_command_palette();
+        (command.run)(self);
     }
 
-    pub fn cancel_editing(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input_buffer.clear();
-        self.editing_task_id = None;
+    // === Column Navigation ===
+
+    /// Collapse or expand the selected column.
+    ///
+    /// Collapsed columns can still be navigated into; the selected column is
+    /// always drawn expanded and collapses again once the selection moves on.
+    pub fn toggle_column_collapsed(&mut self) {
+        if let Some(column) = self.board.columns.get_mut(self.selected_column) {
+            column.collapsed = !column.collapsed;
+            self.save();
+        }
     }
 
-    pub fn handle_char_input(&mut self, c: char) {
-        if self.input_mode == InputMode::Creating
-            || self.input_mode == InputMode::Editing
-            || self.input_mode == InputMode::EditingDescription
-            || self.input_mode == InputMode::AddingTag
-            || self.input_mode == InputMode::CreatingBoard
-        {
-            self.input_buffer.push(c);
+    /// Show only the selected column, or go back to showing all of them
+    pub fn toggle_focus_mode(&mut self) {
+        self.board.focus_mode = !self.board.focus_mode;
+        self.save();
+    }
+
+    /// Keep the selected column sorted oldest first, keeping the selected task selected
+    pub fn sort_column_by_age(&mut self) {
+        let selected_id = self.selected_task_id();
+        let snapshot = self.board.clone();
+        if self.board.set_sort_mode(self.selected_column, SortMode::Age).is_err() {
+            return;
+        }
+        self.status_message = Some(format!("Sort: {}", SortMode::Age));
+
+        // A column already sorted by age has nothing to undo
+        if self.board.columns[self.selected_column] != snapshot.columns[self.selected_column] {
+            self.push_undo(snapshot);
+            self.reselect_task(selected_id);
+            self.save();
         }
     }
 
-    pub fn handle_backspace(&mut self) {
-        if self.input_mode == InputMode::Creating
-            || self.input_mode == InputMode::Editing
-            || self.input_mode == InputMode::EditingDescription
-            || self.input_mode == InputMode::AddingTag
-            || self.input_mode == InputMode::CreatingBoard
-        {
-            self.input_buffer.pop();
+    /// Cycle the selected column's sort mode, which it keeps across sessions
+    pub fn cycle_sort_mode(&mut self) {
+        let Some(column) = self.board.columns.get(self.selected_column) else {
+            return;
+        };
+        let sort_mode = column.sort_mode.next();
+        let selected_id = self.selected_task_id();
+
+        self.checkpoint();
+        if self.board.set_sort_mode(self.selected_column, sort_mode).is_ok() {
+            self.reselect_task(selected_id);
+            self.status_message = Some(format!("Sort: {}", sort_mode));
+            self.save();
         }
     }
 
-    // === Task Viewing ===
+    /// Select a task in the selected column by id, e.g. after it was re-sorted
+    fn select_task_by_id(&mut self, task_id: usize) {
+        self.selected_task_index = self.board.columns[self.selected_column].task_index(task_id);
+    }
 
-    pub fn start_viewing(&mut self) {
-        if self.selected_task_index.is_some() {
-            self.input_mode = InputMode::Viewing;
+    pub fn next_column(&mut self) {
+        let wrap = self.config.wrap_navigation;
+        if let Some(column) = step(self.selected_column, self.board.columns.len(), true, wrap) {
+            self.selected_column = column;
+            self.update_task_selection();
         }
     }
 
-    pub fn stop_viewing(&mut self) {
-        self.input_mode = InputMode::Normal;
+    pub fn previous_column(&mut self) {
+        let wrap = self.config.wrap_navigation;
+        if let Some(column) = step(self.selected_column, self.board.columns.len(), false, wrap) {
+            self.selected_column = column;
+            self.update_task_selection();
+        }
     }
 
-    // === Task Metadata ===
+    pub fn update_task_selection(&mut self) {
+        // Auto-select first visible task if column has any, otherwise clear selection
+        self.selected_task_index = self.visible_task_indices(self.selected_column).first().copied();
+    }
 
-    pub fn cycle_priority(&mut self) {
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
-            if task_idx < column.tasks.len() {
-                let task_id = column.tasks[task_idx].id;
-                let _ = self.board.cycle_task_priority(self.selected_column, task_id);
+    /// Indices of the tasks in a column that pass the active filter
+    pub fn visible_task_indices(&self, column_index: usize) -> Vec<usize> {
+        self.board.columns[column_index]
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| self.filter.matches(task))
+            .filter(|(_, task)| self.config.not_started != NotStartedStyle::Hide || task.is_started())
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Select a task by id after the visible set changed (filter, sort, undo, ...).
+    ///
+    /// If the task is gone or hidden, the nearest visible task to the current
+    /// index is selected instead.
+    fn reselect_task(&mut self, task_id: Option<usize>) {
+        let visible = self.visible_task_indices(self.selected_column);
+        let found = task_id
+            .and_then(|id| self.board.columns[self.selected_column].task_index(id))
+            .filter(|idx| visible.contains(idx));
+
+        match found {
+            Some(idx) => self.selected_task_index = Some(idx),
+            None => self.reselect_near(self.selected_task_index.unwrap_or(0)),
+        }
+    }
+
+    /// Select the first visible task at or after `task_idx`, falling back to the
+    /// last visible task before it
+    fn reselect_near(&mut self, task_idx: usize) {
+        let visible = self.visible_task_indices(self.selected_column);
+        self.selected_task_index = visible
+            .iter()
+            .find(|&&idx| idx >= task_idx)
+            .or(visible.last())
+            .copied();
+    }
+
+    // === Task Navigation ===
+
+    pub fn next_task(&mut self) {
+        let visible = self.visible_task_indices(self.selected_column);
+        if visible.is_empty() {
+            return;
+        }
+
+        let position = self
+            .selected_task_index
+            .and_then(|idx| visible.iter().position(|&v| v == idx));
+        let wrap = self.config.wrap_navigation;
+        self.selected_task_index = Some(match position {
+            Some(pos) => visible[step(pos, visible.len(), true, wrap).unwrap_or(pos)],
+            None => visible[0],
+        });
+    }
+
+    pub fn previous_task(&mut self) {
+        let visible = self.visible_task_indices(self.selected_column);
+        if visible.is_empty() {
+            return;
+        }
+
+        let position = self
+            .selected_task_index
+            .and_then(|idx| visible.iter().position(|&v| v == idx));
+        let wrap = self.config.wrap_navigation;
+        self.selected_task_index = Some(match position {
+            Some(pos) => visible[step(pos, visible.len(), false, wrap).unwrap_or(pos)],
+            None => visible[0],
+        });
+    }
+
+    /// Jump to the next overdue task, scanning columns left to right and
+    /// wrapping around to the first.
+    ///
+    /// Tasks in the done column and tasks hidden by the filter are skipped.
+    pub fn select_next_overdue(&mut self) {
+        let board = &self.board;
+        let overdue: Vec<(usize, usize)> = (0..board.columns.len())
+            .filter(|&col| !board.is_done_column(col))
+            .flat_map(|col| {
+                self.visible_task_indices(col)
+                    .into_iter()
+                    .filter(move |&idx| board.columns[col].tasks[idx].is_overdue())
+                    .map(move |idx| (col, idx))
+            })
+            .collect();
+
+        let current = self.selected_task_index.map(|idx| (self.selected_column, idx));
+        let next = overdue
+            .iter()
+            .find(|&&position| current.is_none_or(|current| position > current))
+            .or(overdue.first());
+
+        match next {
+            Some(&(col, idx)) => {
+                self.selected_column = col;
+                self.selected_task_index = Some(idx);
+            }
+            None => self.status_message = Some("No overdue tasks".to_string()),
+        }
+    }
+
+    // === Task Management ===
+
+    pub fn delete_selected_task(&mut self) {
+        let (Some(task_idx), Some(task_id)) = (self.selected_task_index, self.selected_task_id()) else {
+            return;
+        };
+
+        // Remove the task
+        self.checkpoint();
+        self.board.columns[self.selected_column].remove_task(task_id);
+
+        // Adjust selection after deletion: the same index now points to the
+        // next task; if we deleted the last task, select the new last task
+        self.reselect_near(task_idx);
+
+        // Save after deletion
+        self.save();
+    }
+
+    /// Move the selected task into the board's archive
+    pub fn archive_selected_task(&mut self) {
+        let (Some(task_idx), Some(task_id)) = (self.selected_task_index, self.selected_task_id()) else {
+            return;
+        };
+        let snapshot = self.board.clone();
+        let result = self.board.archive_task(self.selected_column, task_id);
+        if self.report(result).is_some() {
+            self.push_undo(snapshot);
+            self.reselect_near(task_idx);
+            self.save();
+        }
+    }
+
+    /// Copy the selected task into the clipboard
+    pub fn yank_task(&mut self) {
+        if let Some(task) = self.selected_task().cloned() {
+            self.status_message = Some(format!("Yanked '{}'", task.title));
+            self.clipboard = Some(task);
+        }
+    }
+
+    /// Paste the clipboard task into the selected column with a fresh id
+    pub fn paste_task(&mut self) {
+        let Some(task) = self.clipboard.clone() else {
+            return;
+        };
+
+        self.checkpoint();
+        if let Ok(task_id) = self.board.insert_task_copy(self.selected_column, &task) {
+            self.select_task_by_id(task_id);
+            self.status_message = Some(format!("Pasted '{}'", task.title));
+            self.save();
+        }
+    }
+
+    pub fn move_task_left(&mut self) {
+        // Can't move left from first column
+        if self.selected_column == 0 {
+            return;
+        }
+
+        if let Some(task_id) = self.selected_task_id() {
+            let from_column = self.selected_column;
+            let to_column = self.selected_column - 1;
+
+            // Move the task
+            let snapshot = self.board.clone();
+            if self.board.move_task(from_column, to_column, task_id).is_ok() {
+                self.push_undo(snapshot);
+
+                // Update selected column
+                self.selected_column = to_column;
+
+                // Find the moved task in the new column and select it
+                self.select_task_by_id(task_id);
+
+                // Save after move
                 self.save();
             }
         }
     }
 
-    pub fn start_editing_description(&mut self) {
-        if let Some(task_idx) = self.selected_task_index {
-            let column = &self.board.columns[self.selected_column];
-            if task_idx < column.tasks.len() {
-                let task = &column.tasks[task_idx];
-                self.editing_task_id = Some(task.id);
-                self.input_buffer = task.description.clone().unwrap_or_default();
-                self.input_mode = InputMode::EditingDescription;
+    pub fn move_task_right(&mut self) {
+        // Can't move right from last column
+        if self.selected_column >= self.board.columns.len() - 1 {
+            return;
+        }
+
+        if let Some(task_id) = self.selected_task_id() {
+            let from_column = self.selected_column;
+            let to_column = self.selected_column + 1;
+
+            // Move the task
+            let snapshot = self.board.clone();
+            if self.board.move_task(from_column, to_column, task_id).is_ok() {
+                self.push_undo(snapshot);
+
+                // Update selected column
+                self.selected_column = to_column;
+
+                // Find the moved task in the new column and select it
+                self.select_task_by_id(task_id);
+
+                // Save after move
+                self.save();
             }
         }
     }
 
-    pub fn save_description(&mut self) {
-        if let Some(task_id) = self.editing_task_id {
-            let _ = self.board.update_task_description(
-                self.selected_column,
-                task_id,
-                &self.input_buffer,
-            );
+    /// Move the selected task straight to the done column, keeping it selected.
+    ///
+    /// A task that's already done goes back to the first column instead, so
+    /// the same key reopens a task finished by mistake.
+    pub fn toggle_selected_done(&mut self) {
+        let Some(task_id) = self.selected_task_id() else {
+            return;
+        };
+        let from_column = self.selected_column;
+        let to_column = if self.board.is_done_column(from_column) {
+            0
+        } else {
+            match self.board.done_column_index() {
+                Some(done) => done,
+                None => {
+                    self.status_message = Some("This board has no done column".to_string());
+                    return;
+                }
+            }
+        };
+        if to_column == from_column {
+            return;
+        }
+
+        let snapshot = self.board.clone();
+        let result = self.board.move_task(from_column, to_column, task_id);
+        if self.report(result).is_some() {
+            self.push_undo(snapshot);
+            self.selected_column = to_column;
+            self.select_task_by_id(task_id);
+            self.save();
+        }
+    }
+
+    // === Task Creation/Editing ===
+
+    pub fn start_creating(&mut self) {
+        self.input_mode = InputMode::Creating;
+        self.input_buffer.clear();
+        self.history_recall = None;
+    }
+
+    /// Check the title being entered against the configured maximum length,
+    /// explaining any rejection in the status bar
+    fn validate_title_input(&mut self) -> bool {
+        let max = self.config.max_title_len;
+        let len = self.input_buffer.chars().count();
+        if max > 0 && len > max {
+            self.status_message = Some(format!("Title too long ({} > {} characters)", len, max));
+            return false;
+        }
+        true
+    }
+
+    /// Resolve the configured column for new tasks, warning and falling back
+    /// to the selected column if it doesn't exist
+    fn new_task_column(&mut self) -> usize {
+        let target = match &self.config.new_task_column {
+            NewTaskTarget::Selected => return self.selected_column,
+            NewTaskTarget::FixedIndex(idx) => {
+                Some(*idx).filter(|&idx| idx < self.board.columns.len())
+            }
+            NewTaskTarget::FixedName(name) => self.board.column_index_by_name(name),
+        };
+
+        target.unwrap_or_else(|| {
+            self.status_message = Some(format!(
+                "New task column {:?} not found; using the selected column",
+                self.config.new_task_column
+            ));
+            self.selected_column
+        })
+    }
+
+    pub fn create_task(&mut self) {
+        self.history_recall = None;
+        if !self.validate_title_input() {
+            // Stay in create mode so the title can be shortened
+            return;
+        }
+        if !self.input_buffer.is_empty() {
+            let snapshot = self.board.clone();
+            let column = self.new_task_column();
+            let task_id = match self.board.add_task(column, &self.input_buffer) {
+                Ok(task_id) => task_id,
+                Err(e) => {
+                    // Keep the typed title so nothing is lost, and fix up the selection
+                    self.status_message = Some(format!("Could not create task: {}", e));
+                    self.clamp_selection();
+                    return;
+                }
+            };
+            self.push_undo(snapshot);
+            let title = std::mem::take(&mut self.input_buffer);
+            self.board.record_title(&title);
+
+            // Select the newly created task (wherever the column's sort put it) if
+            // it's in the selected column; tasks sent elsewhere leave the cursor alone
+            if column == self.selected_column {
+                self.select_task_by_id(task_id);
+            }
+
+            // Save after creation
+            self.save();
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn cancel_creating(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.history_recall = None;
+    }
+
+    /// Open the quick capture line, remembering the current mode and input
+    /// so they can be restored afterwards
+    pub fn start_quick_capture(&mut self) {
+        if self.input_mode == InputMode::Capturing {
+            return;
+        }
+        let mode = std::mem::replace(&mut self.input_mode, InputMode::Capturing);
+        let input = std::mem::take(&mut self.input_buffer);
+        self.capture_return = Some((mode, input));
+    }
+
+    /// Add the captured title to the new-task column without moving the
+    /// selection, then return to the previous mode
+    pub fn finish_quick_capture(&mut self) {
+        if !self.validate_title_input() {
+            return;
+        }
+        let title = self.input_buffer.trim().to_string();
+        if !title.is_empty() {
+            let selected = self.selected_task_id();
+            self.checkpoint();
+            let column = self.new_task_column();
+            let result = self.board.add_task(column, &title);
+            if self.report(result).is_some() {
+                self.board.record_title(&title);
+                self.status_message = Some(format!("Captured '{}'", title));
+            }
+            // A sorted column may have shuffled the selected task
+            if let Some(task_id) = selected {
+                self.select_task_by_id(task_id);
+            }
             self.save();
         }
+        self.end_quick_capture();
+    }
+
+    pub fn cancel_quick_capture(&mut self) {
+        self.end_quick_capture();
+    }
+
+    fn end_quick_capture(&mut self) {
+        let (mode, input) = self.capture_return.take().unwrap_or((InputMode::Normal, String::new()));
+        self.input_mode = mode;
+        self.input_buffer = input;
+    }
+
+    /// Step back to an older title from the board's history (Up in create mode)
+    pub fn recall_previous_title(&mut self) {
+        let history = self.board.title_history();
+        if history.is_empty() {
+            return;
+        }
+
+        let position = match &self.history_recall {
+            Some((position, _)) => (*position + 1).min(history.len() - 1),
+            None => 0,
+        };
+        let title = history[history.len() - 1 - position].clone();
+        match &mut self.history_recall {
+            Some((current, _)) => *current = position,
+            None => self.history_recall = Some((position, self.input_buffer.clone())),
+        }
+        self.input_buffer = title;
+    }
+
+    /// Step forward to a newer title, restoring the draft past the most recent (Down)
+    pub fn recall_next_title(&mut self) {
+        match self.history_recall.take() {
+            Some((0, draft)) => self.input_buffer = draft,
+            Some((position, draft)) => {
+                let history = self.board.title_history();
+                self.input_buffer = history[history.len() - position].clone();
+                self.history_recall = Some((position - 1, draft));
+            }
+            None => {}
+        }
+    }
+
+    pub fn start_editing(&mut self) {
+        if let Some((task_id, text)) = self.selected_task().map(|task| (task.id, task.title.clone())) {
+            self.editing_task_id = Some(task_id);
+            self.input_buffer = text;
+            self.input_mode = InputMode::Editing;
+        }
+    }
+
+    pub fn save_edit(&mut self) {
+        if !self.validate_title_input() {
+            // Stay in edit mode so the title can be shortened
+            return;
+        }
+        if let Some(task_id) = self.editing_task_id {
+            if !self.input_buffer.is_empty() {
+                self.checkpoint();
+                let result = self.board.update_task_title(
+                    self.selected_column,
+                    task_id,
+                    &self.input_buffer,
+                );
+                self.report(result);
+
+                // Save after editing
+                self.save();
+            }
+        }
+
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
         self.editing_task_id = None;
     }
 
-    pub fn cancel_editing_description(&mut self) {
+    pub fn cancel_editing(&mut self) {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
         self.editing_task_id = None;
     }
 
-    pub fn start_adding_tag(&mut self) {
-        if self.selected_task_index.is_some() {
-            self.input_mode = InputMode::AddingTag;
-            self.input_buffer.clear();
+    pub fn handle_char_input(&mut self, c: char) {
+        if self.is_typing() {
+            self.input_buffer.push(c);
+            self.command_selection = 0;
         }
     }
 
-    pub fn add_tag(&mut self) {
-        if let Some(task_idx) = self.selected_task_index {
-            if !self.input_buffer.is_empty() {
-                let column = &self.board.columns[self.selected_column];
-                if task_idx < column.tasks.len() {
-                    let task_id = column.tasks[task_idx].id;
-                    let _ = self.board.add_task_tag(
-                        self.selected_column,
-                        task_id,
-                        &self.input_buffer,
-                    );
-                    self.save();
-                }
-            }
+    pub fn handle_backspace(&mut self) {
+        if self.is_typing() {
+            self.input_buffer.pop();
+            self.command_selection = 0;
         }
+    }
+
+    /// Whether the current mode edits the input buffer
+    fn is_typing(&self) -> bool {
+        matches!(
+            self.input_mode,
+            InputMode::Creating
+                | InputMode::Capturing
+                | InputMode::Editing
+                | InputMode::EditingDescription
+                | InputMode::AddingTag
+                | InputMode::CreatingBoard
+                | InputMode::Filtering
+                | InputMode::TaggingColumn
+                | InputMode::CompletingTag
+                | InputMode::SettingDueDate
+                | InputMode::SettingStartDate
+                | InputMode::SettingFilteredPriority
+                | InputMode::EditingBoardDescription
+                | InputMode::CommandPalette
+        )
+    }
+
+    // === Filtering ===
+
+    pub fn start_filtering(&mut self) {
+        self.input_mode = InputMode::Filtering;
+        self.input_buffer = self.filter.to_string();
+    }
+
+    pub fn apply_filter(&mut self) {
+        let selected_id = self.selected_task_id();
+        self.filter = TaskFilter::parse(&self.input_buffer);
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
+        self.reselect_task(selected_id);
     }
 
-    pub fn cancel_adding_tag(&mut self) {
+    pub fn cancel_filtering(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+
+    /// Ids of the tasks passing the active filter, across all columns
+    pub fn filtered_task_ids(&self) -> Vec<usize> {
+        (0..self.board.columns.len())
+            .flat_map(|column_index| {
+                self.visible_task_indices(column_index)
+                    .into_iter()
+                    .map(move |idx| self.board.columns[column_index].tasks[idx].id)
+            })
+            .collect()
+    }
+
+    /// Ask for a priority to give every task matching the active filter
+    pub fn start_setting_filtered_priority(&mut self) {
+        if !self.filter.is_active() {
+            self.status_message = Some("Filter tasks first to set their priority in bulk".to_string());
+            return;
+        }
+        self.input_mode = InputMode::SettingFilteredPriority;
+        self.input_buffer.clear();
+    }
+
+    /// Set the priority typed into the prompt on every filtered task.
+    ///
+    /// Unknown priority names keep the prompt open so they can be corrected.
+    pub fn set_filtered_priority(&mut self) {
+        let priority = match self.input_buffer.parse::<Priority>() {
+            Ok(priority) => priority,
+            Err(e) => {
+                self.status_message = Some(e);
+                return;
+            }
+        };
+
+        let task_ids = self.filtered_task_ids();
+        let selected_id = self.selected_task_id();
+        self.checkpoint();
+        let updated = self.board.set_priority_for(&task_ids, priority);
+        self.reselect_task(selected_id);
+        self.status_message = Some(format!("Set {} task(s) to {} priority", updated, priority));
+        self.save();
+        self.cancel_setting_filtered_priority();
+    }
+
+    pub fn cancel_setting_filtered_priority(&mut self) {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
     }
+
+    pub fn clear_filter(&mut self) {
+        let selected_id = self.selected_task_id();
+        self.filter = TaskFilter::default();
+        self.reselect_task(selected_id);
+    }
+
+    // === Task Viewing ===
+
+    pub fn start_viewing(&mut self) {
+        if self.selected_task_index.is_some() {
+            self.input_mode = InputMode::Viewing;
+        }
+    }
+
+    pub fn stop_viewing(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    // === Task Metadata ===
+
+    pub fn cycle_priority(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            self.checkpoint();
+            let result = self.board.cycle_task_priority(self.selected_column, task_id);
+            self.report(result);
+            // Keep following the task if a priority sort moved it
+            self.select_task_by_id(task_id);
+            self.save();
+        }
+    }
+
+    pub fn start_editing_description(&mut self) {
+        if let Some((task_id, text)) = self.selected_task().map(|task| (task.id, task.description.clone().unwrap_or_default())) {
+            self.editing_task_id = Some(task_id);
+            self.input_buffer = text;
+            self.input_mode = InputMode::EditingDescription;
+        }
+    }
+
+    /// The selected task, if the selection points at a task
+    pub fn selected_task(&self) -> Option<&Task> {
+        self.board.task_at(self.selected_column, self.selected_task_index?)
+    }
+
+    /// Id of the selected task, if the selection points at a task
+    pub fn selected_task_id(&self) -> Option<usize> {
+        self.board.task_id_at(self.selected_column, self.selected_task_index?)
+    }
+
+    /// Remove the selected task's description in one step
+    pub fn clear_description(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            self.checkpoint();
+            let result = self.board.update_task_description(self.selected_column, task_id, "");
+            self.report(result);
+            self.save();
+        }
+    }
+
+    /// Remove the selected task's due date in one step
+    pub fn clear_due_date(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            self.checkpoint();
+            let result = self.board.set_task_due_date(self.selected_column, task_id, None);
+            self.report(result);
+            self.save();
+        }
+    }
+
+    pub fn start_setting_due_date(&mut self) {
+        if let Some((task_id, text)) = self.selected_task().map(|task| (task.id, task.due_date.clone().unwrap_or_default())) {
+            self.editing_task_id = Some(task_id);
+            self.input_buffer = text;
+            self.input_mode = InputMode::SettingDueDate;
+        }
+    }
+
+    /// Set the edited task's due date from the input buffer; an empty buffer clears it.
+    ///
+    /// Input that isn't `YYYY-MM-DD` or `YYYY-MM-DD HH:MM` is rejected and the
+    /// prompt stays open so it can be corrected.
+    pub fn save_due_date(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        if !input.is_empty() && !Task::is_valid_due_date(&input) {
+            self.status_message = Some("Due date must be YYYY-MM-DD or YYYY-MM-DD HH:MM".to_string());
+            return;
+        }
+
+        if let Some(task_id) = self.editing_task_id {
+            self.checkpoint();
+            let warning = self.stale_due_date_warning(&input);
+            let due_date = (!input.is_empty()).then_some(input);
+            let result = self.board.set_task_due_date(self.selected_column, task_id, due_date);
+            self.report(result);
+            self.save();
+            // Saving may have reported its own status; the warning is only a nudge
+            if self.status_message.is_none() {
+                self.status_message = warning;
+            }
+        }
+        self.cancel_setting_due_date();
+    }
+
+    /// Warning for a due date far enough in the past to likely be a typo.
+    ///
+    /// The date is still set; the warning clears on the next key press like any status.
+    fn stale_due_date_warning(&self, input: &str) -> Option<String> {
+        let max_days = self.config.stale_due_days;
+        if max_days == 0 || !Task::is_stale_due_date(input, i64::from(max_days)) {
+            return None;
+        }
+        let span = match max_days {
+            365 => "a year".to_string(),
+            days => format!("{} days", days),
+        };
+        Some(format!("Due date is over {} ago — sure?", span))
+    }
+
+    pub fn cancel_setting_due_date(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.editing_task_id = None;
+    }
+
+    pub fn start_setting_start_date(&mut self) {
+        if let Some((task_id, text)) = self.selected_task().map(|task| (task.id, task.start_date.clone().unwrap_or_default())) {
+            self.editing_task_id = Some(task_id);
+            self.input_buffer = text;
+            self.input_mode = InputMode::SettingStartDate;
+        }
+    }
+
+    /// Set the edited task's start date from the input buffer; an empty buffer clears it.
+    ///
+    /// Accepts the same formats as due dates. A task pushed into the future
+    /// drops out of view when not-started tasks are hidden.
+    pub fn save_start_date(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        if !input.is_empty() && !Task::is_valid_due_date(&input) {
+            self.status_message = Some("Start date must be YYYY-MM-DD or YYYY-MM-DD HH:MM".to_string());
+            return;
+        }
+
+        if let Some(task_id) = self.editing_task_id {
+            self.checkpoint();
+            let start_date = (!input.is_empty()).then_some(input);
+            let result = self.board.set_task_start_date(self.selected_column, task_id, start_date);
+            self.report(result);
+            self.save();
+            self.reselect_task(Some(task_id));
+        }
+        self.cancel_setting_start_date();
+    }
+
+    pub fn cancel_setting_start_date(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.editing_task_id = None;
+    }
+
+    pub fn save_description(&mut self) {
+        if let Some(task_id) = self.editing_task_id {
+            self.checkpoint();
+            let result = self.board.update_task_description(
+                self.selected_column,
+                task_id,
+                &self.input_buffer,
+            );
+            self.report(result);
+            self.save();
+        }
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.editing_task_id = None;
+    }
+
+    pub fn cancel_editing_description(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+        self.editing_task_id = None;
+    }
+
+    pub fn start_adding_tag(&mut self) {
+        if self.selected_task_index.is_some() {
+            self.input_mode = InputMode::AddingTag;
+            self.input_buffer.clear();
+        }
+    }
+
+    pub fn add_tag(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            if !self.input_buffer.is_empty() {
+                self.checkpoint();
+                let result = self.board.add_task_tag(
+                    self.selected_column,
+                    task_id,
+                    &self.input_buffer,
+                );
+                self.report(result);
+                self.save();
+            }
+        }
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+
+    pub fn cancel_adding_tag(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+    }
+
+    pub fn start_tagging_column(&mut self) {
+        self.input_mode = InputMode::TaggingColumn;
+        self.input_buffer.clear();
+    }
+
+    /// Tag every task in the selected column, or untag them when the input starts with `-`
+    pub fn tag_column(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+
+        let (tag, remove) = match input.strip_prefix('-') {
+            Some(tag) => (tag.trim(), true),
+            None => (input.as_str(), false),
+        };
+        if tag.is_empty() {
+            return;
+        }
+
+        let snapshot = self.board.clone();
+        let result = if remove {
+            self.board.untag_all_in_column(self.selected_column, tag)
+        } else {
+            self.board.tag_all_in_column(self.selected_column, tag)
+        };
+
+        match result {
+            Ok(0) => {
+                self.status_message = Some(format!("No tasks changed for tag '{}'", tag));
+            }
+            Ok(count) => {
+                self.push_undo(snapshot);
+                self.save();
+                let verb = if remove { "Untagged" } else { "Tagged" };
+                self.status_message = Some(format!("{} {} task(s) with '{}'", verb, count, tag));
+            }
+            Err(e) => self.status_message = Some(e),
+        }
+    }
+
+    pub fn start_completing_tag(&mut self) {
+        self.input_mode = InputMode::CompletingTag;
+        self.input_buffer.clear();
+    }
+
+    /// Ask to move every open task with the entered tag (`#` optional) to the done column
+    pub fn request_complete_tagged(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+
+        let tag = input.strip_prefix('#').unwrap_or(&input);
+        if tag.is_empty() {
+            return;
+        }
+
+        let count = self
+            .board
+            .tasks_with_tag(tag)
+            .iter()
+            .filter(|&&(_, col_idx)| !self.board.is_done_column(col_idx))
+            .count();
+        if count == 0 {
+            self.status_message = Some(format!("No open tasks tagged '{}'", tag));
+            return;
+        }
+        self.request_confirmation(ConfirmAction::CompleteTagged(tag.to_string(), count));
+    }
+
+    fn complete_tagged(&mut self, tag: &str) {
+        self.checkpoint();
+        let result = self.board.move_tagged_to_done(tag);
+        if let Some(moved) = self.report(result) {
+            self.status_message = Some(format!("Moved {} task(s) tagged '{}' to done", moved, tag));
+        }
+        self.clamp_selection();
+        self.save();
+    }
+}
+
+/// Whether startup should open the board selector rather than the active
+/// board: only with `start_in_selector` on, more than one board to pick from,
+/// and no board named on the command line
+fn starts_in_selector(config: &Config, board_count: usize, board_requested: bool) -> bool {
+    config.start_in_selector && board_count > 1 && !board_requested
+}
+
+/// Move `current` one step forward or back within `0..len`.
+///
+/// Past either end it wraps around when `wrap` is set and otherwise returns
+/// `None` so the selection stays put.
+fn step(current: usize, len: usize, forward: bool, wrap: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let last = len - 1;
+    match (forward, wrap) {
+        (true, _) if current < last => Some(current + 1),
+        (true, true) => Some(0),
+        (false, _) if current > 0 => Some((current - 1).min(last)),
+        (false, true) => Some(last),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kanban_tui::SortMode;
+    use std::env;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn test_app(config: Config) -> App {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let test_dir = env::temp_dir().join(format!(
+            "kanban-app-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        App::with_storage(Storage::with_path(test_dir), config)
+    }
+
+    fn create(app: &mut App, title: &str) {
+        app.start_creating();
+        app.input_buffer = title.to_string();
+        app.create_task();
+    }
+
+    #[test]
+    fn test_save_hook_fires_after_each_saved_change() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let seen = std::rc::Rc::clone(&calls);
+        let dir = env::temp_dir().join(format!("kanban-app-test-hook-{}", std::process::id()));
+        let storage = Storage::with_path(dir).with_save_hook(move |_, board| {
+            let counts = board.columns.iter().map(|c| c.tasks.len()).collect::<Vec<_>>();
+            seen.borrow_mut().push(counts);
+        });
+        let mut app = App::with_storage(storage, Config::default());
+        // Starting on a fresh directory writes the new default board
+        calls.borrow_mut().clear();
+
+        create(&mut app, "Task");
+        app.move_task_right();
+        app.delete_selected_task();
+        assert_eq!(*calls.borrow(), vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 0]]);
+
+        // Nothing is reported while changes stay unsaved
+        app.config.auto_save = false;
+        create(&mut app, "Draft");
+        assert_eq!(calls.borrow().len(), 3);
+        let _ = std::fs::remove_dir_all(app.storage.storage_location());
+    }
+
+    #[test]
+    fn test_help_scroll_stays_in_bounds() {
+        let mut app = test_app(Config::default());
+        app.show_help();
+        app.scroll_help(-1);
+        assert_eq!(app.help_scroll, 0);
+
+        app.scroll_help(3);
+        assert_eq!(app.help_scroll, 3);
+        app.scroll_help(1000);
+        assert_eq!(usize::from(app.help_scroll), commands::COMMANDS.len());
+
+        app.close_help();
+        app.show_help();
+        assert_eq!(app.help_scroll, 0);
+    }
+
+    #[test]
+    fn test_save_records_success() {
+        let mut app = test_app(Config::default());
+        app.save();
+        assert!(matches!(app.last_save, Some((_, Ok(())))));
+    }
+
+    #[test]
+    fn test_failed_save_updates_indicator_state() {
+        let mut app = test_app(Config::default());
+
+        // Point storage at a regular file so the boards directory can't be created
+        let blocker = env::temp_dir().join(format!("kanban-app-blocker-{}", std::process::id()));
+        std::fs::write(&blocker, "not a directory").unwrap();
+        app.storage = Storage::with_path(blocker.clone());
+
+        app.save();
+        assert!(matches!(app.last_save, Some((_, Err(_)))));
+
+        std::fs::remove_file(blocker).unwrap();
+    }
+
+    #[test]
+    fn test_undo_restores_previous_board() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Task 1");
+        create(&mut app, "Task 2");
+
+        app.undo();
+        assert_eq!(app.board.columns[0].tasks.len(), 1);
+        assert_eq!(app.board.columns[0].tasks[0].title, "Task 1");
+        assert_eq!(app.selected_task_index, Some(0));
+    }
+
+    #[test]
+    fn test_undo_limit_drops_oldest_snapshots() {
+        let mut app = test_app(Config { undo_limit: 3, ..Config::default() });
+        for i in 0..5 {
+            create(&mut app, &format!("Task {}", i));
+        }
+        assert_eq!(app.undo_stack.len(), 3);
+
+        // The retained range can still be undone
+        for _ in 0..3 {
+            app.undo();
+        }
+        assert_eq!(app.board.columns[0].tasks.len(), 2);
+
+        // Older snapshots were dropped
+        app.undo();
+        assert_eq!(app.board.columns[0].tasks.len(), 2);
+        assert_eq!(app.status_message.as_deref(), Some("Nothing to undo"));
+    }
+
+    #[test]
+    fn test_undo_disabled_with_zero_limit() {
+        let mut app = test_app(Config { undo_limit: 0, ..Config::default() });
+        create(&mut app, "Task");
+        assert!(app.undo_stack.is_empty());
+
+        app.undo();
+        assert_eq!(app.board.columns[0].tasks.len(), 1);
+        assert_eq!(app.status_message.as_deref(), Some("undo disabled"));
+    }
+
+    fn app_with_boards(names: &[&str]) -> App {
+        let mut app = test_app(Config::default());
+        for name in names {
+            app.start_creating_board();
+            app.input_buffer = name.to_string();
+            app.create_new_board();
+        }
+        app
+    }
+
+    fn select_board(app: &mut App, name: &str) {
+        app.start_board_selection();
+        app.selected_board_index = app.available_boards.iter().position(|b| b == name);
+    }
+
+    #[test]
+    fn test_copy_columns_from_selected_board() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Idea");
+        app.move_task_right();
+        app.selected_column = 0;
+        create(&mut app, "Dropped");
+        let template = Board::with_columns("Template", vec!["Backlog".to_string(), "In Progress".to_string(), "Done".to_string()]).unwrap();
+        app.storage.save_board("template", &template).unwrap();
+        app.refresh_board_lists();
+
+        select_board(&mut app, "template");
+        app.request_copy_columns();
+        assert_eq!(app.pending_confirmation, Some(ConfirmAction::CopyColumns("template".to_string(), 1)));
+        app.confirm();
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.column_names(), vec!["Backlog", "In Progress", "Done"]);
+        assert_eq!(app.board.columns[1].tasks[0].title, "Idea");
+        assert_eq!(app.board.archived_tasks()[0].title, "Dropped");
+
+        app.undo();
+        assert_eq!(app.board.columns[0].name, "To Do");
+
+        // The open board can't be its own template
+        select_board(&mut app, "default");
+        app.request_copy_columns();
+        assert_eq!(app.pending_confirmation, None);
+    }
+
+    #[test]
+    fn test_archive_board_from_selector() {
+        let mut app = app_with_boards(&["work", "done"]);
+        select_board(&mut app, "work");
+
+        app.toggle_selected_board_archived();
+        assert!(!app.available_boards.contains(&"work".to_string()));
+        assert_eq!(app.archived_boards, vec!["work".to_string()]);
+
+        // The open board stays put
+        select_board(&mut app, "done");
+        app.toggle_selected_board_archived();
+        assert!(app.available_boards.contains(&"done".to_string()));
+        assert!(app.status_message.is_some());
+
+        app.toggle_archived_view();
+        assert_eq!(app.selector_boards(), &["work".to_string()]);
+        assert_eq!(app.selected_board_index, Some(0));
+        app.delete_selected_board();
+        assert!(app.pending_confirmation.is_none());
+        app.toggle_selected_board_archived();
+        assert!(app.available_boards.contains(&"work".to_string()));
+        assert!(app.archived_boards.is_empty());
+        assert_eq!(app.selected_board_index, None);
+    }
+
+    #[test]
+    fn test_opening_archived_board_restores_it() {
+        let mut app = app_with_boards(&["work", "done"]);
+        select_board(&mut app, "work");
+        app.toggle_selected_board_archived();
+
+        app.toggle_archived_view();
+        app.switch_to_selected_board();
+        assert_eq!(app.current_board_name, "work");
+        assert!(app.available_boards.contains(&"work".to_string()));
+        assert!(app.archived_boards.is_empty());
+        assert!(!app.show_archived);
+    }
+
+    #[test]
+    fn test_edit_board_description() {
+        let mut app = test_app(Config::default());
+        app.start_editing_board_description();
+        assert_eq!(app.input_mode, InputMode::EditingBoardDescription);
+        for c in "Q3 tasks".chars() {
+            app.handle_char_input(c);
+        }
+        app.save_board_description();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.description, "Q3 tasks");
+
+        let loaded = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(loaded.description, "Q3 tasks");
+
+        // The prompt starts from the current description
+        app.start_editing_board_description();
+        assert_eq!(app.input_buffer, "Q3 tasks");
+        app.cancel_editing_board_description();
+        assert_eq!(app.board.description, "Q3 tasks");
+
+        app.undo();
+        assert_eq!(app.board.description, "");
+    }
+
+    #[test]
+    fn test_delete_board_requires_confirmation() {
+        let mut app = app_with_boards(&["work"]);
+        select_board(&mut app, "default");
+
+        app.delete_selected_board();
+        assert_eq!(app.input_mode, InputMode::Confirming);
+        assert_eq!(
+            app.pending_confirmation,
+            Some(ConfirmAction::DeleteBoard("default".to_string()))
+        );
+        assert!(app.storage.board_exists("default"));
+    }
+
+    #[test]
+    fn test_delete_board_cancelled_with_no() {
+        let mut app = app_with_boards(&["work"]);
+        select_board(&mut app, "default");
+
+        app.delete_selected_board();
+        app.cancel_confirmation();
+
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        assert!(app.storage.board_exists("default"));
+        assert!(app.available_boards.contains(&"default".to_string()));
+    }
+
+    #[test]
+    fn test_delete_board_confirmed_with_yes() {
+        let mut app = app_with_boards(&["work"]);
+        select_board(&mut app, "default");
+
+        app.delete_selected_board();
+        app.confirm();
+
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        assert!(!app.storage.board_exists("default"));
+        assert_eq!(app.available_boards, vec!["work".to_string()]);
+        assert_eq!(app.current_board_name, "work");
+    }
+
+    #[test]
+    fn test_delete_active_board_switches_to_remaining_board() {
+        let mut app = app_with_boards(&["work", "personal"]);
+        assert_eq!(app.current_board_name, "personal");
+        select_board(&mut app, "personal");
+
+        app.delete_selected_board();
+        app.confirm();
+
+        assert_eq!(app.current_board_name, "default");
+        assert_eq!(app.storage.get_active_board_name().unwrap(), "default");
+        assert!(!app.available_boards.contains(&"personal".to_string()));
+    }
+
+    #[test]
+    fn test_ephemeral_app_opens_given_board() {
+        let mut board = Board::new("piped");
+        board.add_task(0, "From stdin").unwrap();
+
+        let app = App::ephemeral(board, Config::default()).unwrap();
+        assert_eq!(app.current_board_name, "piped");
+        assert_eq!(app.board.columns[0].tasks[0].title, "From stdin");
+        assert!(app.storage.storage_location().starts_with(env::temp_dir()));
+
+        // A second session gets its own directory, which outlives the first one's cleanup
+        let other = App::ephemeral(Board::new("piped"), Config::default()).unwrap();
+        assert_ne!(other.storage.storage_location(), app.storage.storage_location());
+        std::fs::remove_dir_all(app.storage.storage_location()).unwrap();
+        assert!(other.storage.board_exists("piped"));
+        std::fs::remove_dir_all(other.storage.storage_location()).unwrap();
+    }
+
+    #[test]
+    fn test_toggle_previous_board() {
+        let mut app = app_with_boards(&["work", "personal"]);
+        assert_eq!(app.previous_board_name.as_deref(), Some("work"));
+
+        app.toggle_previous_board();
+        assert_eq!(app.current_board_name, "work");
+        app.toggle_previous_board();
+        assert_eq!(app.current_board_name, "personal");
+        app.toggle_previous_board();
+        assert_eq!(app.current_board_name, "work");
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_toggle_previous_board_falls_back_to_selector() {
+        let mut app = test_app(Config::default());
+        app.toggle_previous_board();
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        app.cancel_board_selection();
+
+        // The previous board was deleted behind our back
+        let mut app = app_with_boards(&["work"]);
+        app.storage.delete_board("default").unwrap();
+        app.toggle_previous_board();
+        assert_eq!(app.current_board_name, "work");
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        assert_eq!(app.status_message.as_deref(), Some("Previous board no longer exists"));
+        assert_eq!(app.previous_board_name, None);
+    }
+
+    #[test]
+    fn test_cannot_delete_last_board() {
+        let mut app = test_app(Config::default());
+        select_board(&mut app, "default");
+
+        app.delete_selected_board();
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        assert!(app.pending_confirmation.is_none());
+    }
+
+    fn apply_filter_text(app: &mut App, text: &str) {
+        app.start_filtering();
+        app.input_buffer = text.to_string();
+        app.apply_filter();
+    }
+
+    #[test]
+    fn test_filter_navigation_skips_hidden_tasks() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Fix bug A");
+        create(&mut app, "Write docs");
+        create(&mut app, "Fix bug B");
+
+        apply_filter_text(&mut app, "bug");
+        assert_eq!(app.visible_task_indices(0), vec![0, 2]);
+
+        app.selected_task_index = Some(0);
+        app.next_task();
+        assert_eq!(app.selected_task_index, Some(2));
+        app.next_task();
+        assert_eq!(app.selected_task_index, Some(0));
+        app.previous_task();
+        assert_eq!(app.selected_task_index, Some(2));
+    }
+
+    #[test]
+    fn test_filter_moves_selection_off_hidden_task() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Fix bug");
+        create(&mut app, "Write docs");
+        assert_eq!(app.selected_task_index, Some(1));
+
+        apply_filter_text(&mut app, "bug");
+        assert_eq!(app.selected_task_index, Some(0));
+
+        app.clear_filter();
+        assert!(!app.filter.is_active());
+        assert_eq!(app.visible_task_indices(0), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_toggling_filter_keeps_selected_task() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Write docs");
+        create(&mut app, "Fix bug");
+        create(&mut app, "Fix typo");
+        let fix_bug = app.board.columns[0].tasks[1].id;
+        app.selected_task_index = Some(1);
+
+        apply_filter_text(&mut app, "fix");
+        assert_eq!(app.selected_task_id(), Some(fix_bug));
+
+        // Navigating within the filtered view, then clearing, stays on the same task
+        app.next_task();
+        let fix_typo = app.selected_task_id().unwrap();
+        app.clear_filter();
+        assert_eq!(app.selected_task_id(), Some(fix_typo));
+
+        apply_filter_text(&mut app, "fix");
+        app.cycle_sort_mode();
+        app.cycle_sort_mode();
+        assert_eq!(app.selected_task_id(), Some(fix_typo));
+    }
+
+    #[test]
+    fn test_undo_keeps_selected_task_by_id() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "First");
+        create(&mut app, "Second");
+        let second = app.selected_task_id().unwrap();
+
+        // Undoing a move back into this column re-selects the same task
+        app.selected_task_index = Some(0);
+        app.move_task_right();
+        app.previous_column();
+        app.selected_task_index = Some(0);
+        assert_eq!(app.selected_task_id(), Some(second));
+        app.undo();
+        assert_eq!(app.selected_task_id(), Some(second));
+        assert_eq!(app.selected_task_index, Some(1));
+    }
+
+    #[test]
+    fn test_set_priority_for_filtered_tasks() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Login bug");
+        create(&mut app, "Docs");
+        app.next_column();
+        create(&mut app, "Crash bug");
+        app.previous_column();
+
+        // Without a filter nothing is changed
+        app.start_setting_filtered_priority();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.status_message.is_some());
+
+        apply_filter_text(&mut app, "bug");
+        app.start_setting_filtered_priority();
+        app.input_buffer = "urgent".to_string();
+        app.set_filtered_priority();
+        assert_eq!(app.input_mode, InputMode::SettingFilteredPriority);
+
+        app.input_buffer = "high".to_string();
+        app.set_filtered_priority();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("Set 2 task(s) to High priority"));
+        let priorities: Vec<Priority> = app
+            .board
+            .columns
+            .iter()
+            .flat_map(|column| column.tasks.iter().map(|task| task.priority))
+            .collect();
+        assert_eq!(priorities, vec![Priority::High, Priority::None, Priority::High]);
+
+        app.undo();
+        assert!(app.board.columns[0].tasks.iter().all(|task| task.priority == Priority::None));
+    }
+
+    #[test]
+    fn test_tag_column_adds_and_removes_tags() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "One");
+        create(&mut app, "Two");
+
+        app.start_tagging_column();
+        app.input_buffer = "sprint".to_string();
+        app.tag_column();
+        assert!(app.board.columns[0].tasks.iter().all(|t| t.tags == vec!["sprint"]));
+        assert_eq!(app.status_message.as_deref(), Some("Tagged 2 task(s) with 'sprint'"));
+
+        app.start_tagging_column();
+        app.input_buffer = "-sprint".to_string();
+        app.tag_column();
+        assert!(app.board.columns[0].tasks.iter().all(|t| t.tags.is_empty()));
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_recall_title_history() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "First");
+        create(&mut app, "Second");
+
+        app.start_creating();
+        app.input_buffer = "dra".to_string();
+
+        // Up on the first recall shows the most recent title
+        app.recall_previous_title();
+        assert_eq!(app.input_buffer, "Second");
+        app.recall_previous_title();
+        assert_eq!(app.input_buffer, "First");
+        // Stops at the oldest entry
+        app.recall_previous_title();
+        assert_eq!(app.input_buffer, "First");
+
+        app.recall_next_title();
+        assert_eq!(app.input_buffer, "Second");
+        // Past the most recent restores the draft
+        app.recall_next_title();
+        assert_eq!(app.input_buffer, "dra");
+        app.recall_next_title();
+        assert_eq!(app.input_buffer, "dra");
+    }
+
+    #[test]
+    fn test_title_history_persists_with_board() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Saved title");
+
+        let loaded = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(loaded.title_history(), ["Saved title"]);
+    }
+
+    /// Simulate another program writing the board file after our last save
+    fn edit_externally(app: &mut App, title: &str) {
+        let mut external = app.board.clone();
+        external.add_task(0, title).unwrap();
+        app.storage.save_board(&app.current_board_name, &external).unwrap();
+        app.board_mtime = app.board_mtime.map(|t| t - std::time::Duration::from_secs(5));
+    }
+
+    fn manual_save_app() -> App {
+        test_app(Config {
+            auto_save: false,
+            ..Config::default()
+        })
+    }
+
+    fn tasks_on_disk(app: &App) -> usize {
+        let board = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        board.total_tasks()
+    }
+
+    #[test]
+    fn test_manual_save_only_writes_on_request() {
+        let mut app = manual_save_app();
+        create(&mut app, "Draft");
+        assert!(app.dirty);
+        assert_eq!(tasks_on_disk(&app), 0);
+
+        app.save_now();
+        assert!(!app.dirty);
+        assert_eq!(tasks_on_disk(&app), 1);
+    }
+
+    #[test]
+    fn test_auto_save_keeps_board_clean() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Saved");
+        assert!(!app.dirty);
+        assert_eq!(tasks_on_disk(&app), 1);
+    }
+
+    #[test]
+    fn test_quit_with_unsaved_changes_asks_first() {
+        let mut app = manual_save_app();
+        app.request_quit();
+        assert!(app.should_quit);
+
+        let mut app = manual_save_app();
+        create(&mut app, "Draft");
+        app.request_quit();
+        assert!(!app.should_quit);
+        assert_eq!(
+            app.pending_confirmation,
+            Some(ConfirmAction::UnsavedChanges(LeaveAction::Quit))
+        );
+
+        app.cancel_confirmation();
+        assert!(!app.should_quit);
+        assert!(app.dirty);
+
+        // Saving writes the board, then quits
+        app.request_quit();
+        app.confirm();
+        assert!(app.should_quit);
+        assert_eq!(tasks_on_disk(&app), 1);
+    }
+
+    #[test]
+    fn test_ctrl_c_with_unsaved_changes_asks_first() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let mut app = manual_save_app();
+        create(&mut app, "Draft");
+        app.start_editing();
+        assert_eq!(app.input_mode, InputMode::Editing);
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(!crate::input::handle_key_event(&mut app, ctrl_c));
+        assert_eq!(
+            app.pending_confirmation,
+            Some(ConfirmAction::UnsavedChanges(LeaveAction::Quit))
+        );
+        assert_eq!(app.input_mode, InputMode::Confirming);
+
+        // With nothing unsaved it still quits straight away
+        let mut app = manual_save_app();
+        app.start_creating();
+        assert!(crate::input::handle_key_event(&mut app, ctrl_c));
+    }
+
+    #[test]
+    fn test_discarding_unsaved_changes_on_quit() {
+        let mut app = manual_save_app();
+        create(&mut app, "Draft");
+        app.request_quit();
+        app.discard_unsaved_changes();
+        assert!(app.should_quit);
+        assert_eq!(tasks_on_disk(&app), 0);
+    }
+
+    #[test]
+    fn test_switching_board_with_unsaved_changes() {
+        let mut app = manual_save_app();
+        create(&mut app, "Draft");
+
+        app.start_creating_board();
+        app.input_buffer = "work".to_string();
+        app.create_new_board();
+        app.surface_pending_confirmation();
+        assert_eq!(app.current_board_name, "default");
+        assert_eq!(
+            app.pending_confirmation,
+            Some(ConfirmAction::UnsavedChanges(LeaveAction::SwitchBoard("work".to_string())))
+        );
+
+        app.confirm();
+        assert_eq!(app.current_board_name, "work");
+        assert!(!app.dirty);
+        let saved = app.storage.load_board("default").unwrap().unwrap();
+        assert_eq!(saved.total_tasks(), 1);
+    }
+
+    #[test]
+    fn test_save_detects_external_change() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Local");
+        assert!(!app.has_external_change());
+
+        edit_externally(&mut app, "External");
+        assert!(app.has_external_change());
+
+        create(&mut app, "Another local");
+        app.surface_pending_confirmation();
+        assert_eq!(app.pending_confirmation, Some(ConfirmAction::ExternalChange));
+        assert_eq!(app.input_mode, InputMode::Confirming);
+
+        // The external edit was not clobbered
+        let on_disk = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(on_disk.columns[0].tasks[1].title, "External");
+    }
+
+    #[test]
+    fn test_external_change_overwrite_and_reload() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Local");
+
+        edit_externally(&mut app, "External");
+        app.save();
+        app.confirm();
+        let on_disk = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(on_disk.columns[0].tasks.len(), 1);
+        assert!(!app.has_external_change());
+
+        edit_externally(&mut app, "External");
+        app.save();
+        app.reload_after_external_change();
+        assert_eq!(app.board.columns[0].tasks[1].title, "External");
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app.has_external_change());
+    }
+
+    #[test]
+    fn test_over_length_title_is_rejected() {
+        let mut app = test_app(Config { max_title_len: 5, ..Config::default() });
+
+        create(&mut app, "Too long");
+        assert!(app.board.columns[0].tasks.is_empty());
+        assert_eq!(app.input_mode, InputMode::Creating);
+        assert_eq!(app.input_buffer, "Too long");
+        assert!(app.status_message.as_deref().unwrap().contains("too long"));
+
+        create(&mut app, "Short");
+        app.start_editing();
+        app.input_buffer = "Longer".to_string();
+        app.save_edit();
+        assert_eq!(app.input_mode, InputMode::Editing);
+        assert_eq!(app.board.columns[0].tasks[0].title, "Short");
+    }
+
+    #[test]
+    fn test_title_at_limit_is_accepted() {
+        let mut app = test_app(Config { max_title_len: 5, ..Config::default() });
+        create(&mut app, "Fives");
+        assert_eq!(app.board.columns[0].tasks[0].title, "Fives");
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        let mut app = test_app(Config { max_title_len: 0, ..Config::default() });
+        create(&mut app, &"x".repeat(1000));
+        assert_eq!(app.board.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_yank_and_paste_across_boards() {
+        let mut app = app_with_boards(&["first"]);
+        create(&mut app, "Shared");
+        app.cycle_priority();
+        app.start_adding_tag();
+        app.input_buffer = "ops".to_string();
+        app.add_tag();
+        let original = app.board.columns[0].tasks[0].clone();
+
+        app.yank_task();
+        app.start_creating_board();
+        app.input_buffer = "second".to_string();
+        app.create_new_board();
+        assert_eq!(app.current_board_name, "second");
+        create(&mut app, "Local");
+        app.paste_task();
+
+        let pasted = app.board.columns[0].tasks.last().unwrap();
+        assert_eq!(pasted.title, original.title);
+        assert_eq!(pasted.priority, original.priority);
+        assert_eq!(pasted.tags, original.tags);
+        assert_ne!(pasted.id, app.board.columns[0].tasks[0].id);
+        assert_eq!(app.selected_task_index, Some(1));
+
+        // The clipboard is kept, so pasting again adds another copy
+        app.paste_task();
+        assert_eq!(app.board.columns[0].tasks.len(), 3);
+    }
+
+    #[test]
+    fn test_paste_without_yank_is_noop() {
+        let mut app = test_app(Config::default());
+        app.paste_task();
+        assert!(app.board.columns[0].tasks.is_empty());
+        assert!(app.undo_stack.is_empty());
+    }
+
+    #[test]
+    fn test_delete_board_without_confirmation() {
+        let mut app = app_with_boards(&["work"]);
+        app.config.confirm_destructive = false;
+        select_board(&mut app, "default");
+
+        app.delete_selected_board();
+        assert_eq!(app.input_mode, InputMode::SelectingBoard);
+        assert!(app.pending_confirmation.is_none());
+        assert_eq!(app.available_boards, vec!["work".to_string()]);
+    }
+
+    #[test]
+    fn test_external_change_prompts_even_without_confirmations() {
+        let mut app = test_app(Config { confirm_destructive: false, ..Config::default() });
+        create(&mut app, "Local");
+        edit_externally(&mut app, "External");
+
+        app.save();
+        assert_eq!(app.pending_confirmation, Some(ConfirmAction::ExternalChange));
+    }
+
+    #[test]
+    fn test_clear_description_and_due_date() {
+        use chrono::NaiveDate;
+        use kanban_tui::clock::{self, FixedClock};
+
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let mut app = test_app(Config::default());
+        {
+            let _clock = clock::set_thread_clock(FixedClock(day(1)));
+            create(&mut app, "Task");
+            let id = app.board.columns[0].tasks[0].id;
+            app.board.update_task_description(0, id, "Details").unwrap();
+            app.board.set_task_due_date(0, id, Some("2024-02-01".to_string())).unwrap();
+        }
+        let created = app.board.columns[0].tasks[0].updated_at.clone();
+
+        let _clock = clock::set_thread_clock(FixedClock(day(2)));
+        app.start_viewing();
+        app.clear_description();
+        let task = &app.board.columns[0].tasks[0];
+        assert_eq!(task.description, None);
+        assert_ne!(task.updated_at, created);
+
+        let _clock = clock::set_thread_clock(FixedClock(day(3)));
+        app.clear_due_date();
+        let task = &app.board.columns[0].tasks[0];
+        assert_eq!(task.due_date, None);
+        assert_eq!(task.updated_at, "2024-01-03 09:00:00");
+        assert_eq!(app.input_mode, InputMode::Viewing);
+    }
+
+    #[test]
+    fn test_toggle_column_collapsed_persists() {
+        let mut app = test_app(Config::default());
+        app.selected_column = 2;
+        app.toggle_column_collapsed();
+        assert!(app.board.columns[2].collapsed);
+
+        let loaded = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert!(loaded.columns[2].collapsed);
+
+        // Navigation still enters the collapsed column
+        app.selected_column = 1;
+        app.next_column();
+        assert_eq!(app.selected_column, 2);
+
+        app.toggle_column_collapsed();
+        assert!(!app.board.columns[2].collapsed);
+    }
+
+    #[test]
+    fn test_sort_column_by_age_keeps_selection_and_undoes() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Newer");
+        create(&mut app, "Older");
+        app.board.columns[0].tasks[0].created_at = "2024-02-01 10:00:00".to_string();
+        app.board.columns[0].tasks[1].created_at = "2024-01-01 10:00:00".to_string();
+        app.selected_task_index = Some(0);
+
+        app.sort_column_by_age();
+        let titles: Vec<&str> = app.board.columns[0].tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Older", "Newer"]);
+        assert_eq!(app.selected_task_index, Some(1));
+        assert_eq!(app.board.columns[0].sort_mode, SortMode::Age);
+
+        // Sorting again changes nothing, so leaves no undo entry
+        let undo_depth = app.undo_stack.len();
+        app.sort_column_by_age();
+        assert_eq!(app.undo_stack.len(), undo_depth);
+
+        // The order sticks when tasks are added
+        create(&mut app, "Newest");
+        assert_eq!(app.board.columns[0].tasks[2].title, "Newest");
+
+        app.undo();
+        app.undo();
+        assert_eq!(app.board.columns[0].tasks[0].title, "Newer");
+        assert_eq!(app.board.columns[0].sort_mode, SortMode::Manual);
+    }
+
+    #[test]
+    fn test_set_due_date_with_time() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let press = |app: &mut App, code: KeyCode| {
+            crate::input::handle_key_event(app, KeyEvent::new(code, KeyModifiers::NONE));
+        };
+        let mut app = test_app(Config::default());
+        create(&mut app, "Call");
+
+        press(&mut app, KeyCode::Char('@'));
+        assert_eq!(app.input_mode, InputMode::SettingDueDate);
+        for c in "2024-05-01 15:00".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.columns[0].tasks[0].due_date.as_deref(), Some("2024-05-01 15:00"));
+
+        // Erasing the prompt removes the due date
+        press(&mut app, KeyCode::Char('@'));
+        assert_eq!(app.input_buffer, "2024-05-01 15:00");
+        for _ in 0.."2024-05-01 15:00".len() {
+            press(&mut app, KeyCode::Backspace);
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.board.columns[0].tasks[0].due_date, None);
+    }
+
+    #[test]
+    fn test_set_start_date_hides_task_until_it_starts() {
+        let now = chrono::NaiveDate::from_ymd_opt(2025, 6, 15)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let _clock = kanban_tui::clock::set_thread_clock(kanban_tui::clock::FixedClock(now));
+        let mut app = test_app(Config { not_started: NotStartedStyle::Hide, ..Config::default() });
+        create(&mut app, "Today");
+        create(&mut app, "Later");
+
+        app.start_setting_start_date();
+        assert_eq!(app.input_mode, InputMode::SettingStartDate);
+        app.input_buffer = "next week".to_string();
+        app.save_start_date();
+        assert_eq!(app.input_mode, InputMode::SettingStartDate);
+
+        app.input_buffer = "2025-06-20".to_string();
+        app.save_start_date();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.columns[0].tasks[1].start_date.as_deref(), Some("2025-06-20"));
+        assert_eq!(app.visible_task_indices(0), vec![0]);
+        assert_eq!(app.selected_task_index, Some(0));
+
+        app.config.not_started = NotStartedStyle::Dim;
+        assert_eq!(app.visible_task_indices(0), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_invalid_due_date_keeps_prompt_open() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Call");
+
+        app.start_setting_due_date();
+        app.input_buffer = "3pm".to_string();
+        app.save_due_date();
+        assert_eq!(app.input_mode, InputMode::SettingDueDate);
+        assert!(app.status_message.is_some());
+        assert_eq!(app.board.columns[0].tasks[0].due_date, None);
+    }
+
+    #[test]
+    fn test_stale_due_date_is_set_with_warning() {
+        let now = chrono::NaiveDate::from_ymd_opt(2025, 6, 15)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let _clock = kanban_tui::clock::set_thread_clock(kanban_tui::clock::FixedClock(now));
+        let mut app = test_app(Config::default());
+        create(&mut app, "Typo");
+
+        app.start_setting_due_date();
+        app.input_buffer = "2023-06-01".to_string();
+        app.save_due_date();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.columns[0].tasks[0].due_date.as_deref(), Some("2023-06-01"));
+        assert_eq!(app.status_message.as_deref(), Some("Due date is over a year ago — sure?"));
+
+        // The next key press dismisses it
+        app.status_message = None;
+        app.start_setting_due_date();
+        app.input_buffer = "2025-06-01".to_string();
+        app.save_due_date();
+        assert_eq!(app.status_message, None);
+
+        app.config.stale_due_days = 0;
+        app.start_setting_due_date();
+        app.input_buffer = "2000-01-01".to_string();
+        app.save_due_date();
+        assert_eq!(app.status_message, None);
+    }
+
+    #[test]
+    fn test_select_next_overdue_traverses_columns_and_wraps() {
+        let now = chrono::NaiveDate::from_ymd_opt(2025, 6, 15)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let _clock = kanban_tui::clock::set_thread_clock(kanban_tui::clock::FixedClock(now));
+        let mut app = test_app(Config::default());
+        let overdue = |app: &mut App, col: usize, title: &str, due: &str| {
+            let id = app.board.add_task(col, title).unwrap();
+            app.board.set_task_due_date(col, id, Some(due.to_string())).unwrap();
+            id
+        };
+        let first = overdue(&mut app, 0, "First", "2025-06-01");
+        overdue(&mut app, 0, "Not yet", "2025-07-01");
+        let second = overdue(&mut app, 1, "Second", "2025-06-10");
+        let third = overdue(&mut app, 1, "Third", "2025-06-14");
+        overdue(&mut app, 2, "Finished late", "2025-06-01");
+
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            app.select_next_overdue();
+            visited.push(app.selected_task_id());
+        }
+        assert_eq!(visited, vec![Some(first), Some(second), Some(third), Some(first)]);
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.status_message, None);
+    }
+
+    #[test]
+    fn test_select_next_overdue_without_overdue_tasks() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "No due date");
+        app.select_next_overdue();
+        assert_eq!(app.selected_task_index, Some(0));
+        assert_eq!(app.status_message.as_deref(), Some("No overdue tasks"));
+    }
+
+    #[test]
+    fn test_navigation_wraps_by_default() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "One");
+        create(&mut app, "Two");
+
+        app.selected_task_index = Some(1);
+        app.next_task();
+        assert_eq!(app.selected_task_index, Some(0));
+        app.previous_task();
+        assert_eq!(app.selected_task_index, Some(1));
+
+        app.selected_column = 2;
+        app.next_column();
+        assert_eq!(app.selected_column, 0);
+        app.previous_column();
+        assert_eq!(app.selected_column, 2);
+    }
+
+    #[test]
+    fn test_navigation_stops_at_ends_without_wrap() {
+        let mut app = test_app(Config {
+            wrap_navigation: false,
+            ..Config::default()
+        });
+        create(&mut app, "One");
+        create(&mut app, "Two");
+
+        app.selected_task_index = Some(1);
+        app.next_task();
+        assert_eq!(app.selected_task_index, Some(1));
+        app.previous_task();
+        app.previous_task();
+        assert_eq!(app.selected_task_index, Some(0));
+
+        // Stopping at the first column keeps the task selection too
+        app.previous_column();
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_task_index, Some(0));
+
+        app.selected_column = 2;
+        app.next_column();
+        assert_eq!(app.selected_column, 2);
+        app.previous_column();
+        assert_eq!(app.selected_column, 1);
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(step(0, 3, true, false), Some(1));
+        assert_eq!(step(2, 3, true, false), None);
+        assert_eq!(step(2, 3, true, true), Some(0));
+        assert_eq!(step(0, 3, false, false), None);
+        assert_eq!(step(0, 3, false, true), Some(2));
+        assert_eq!(step(0, 0, true, true), None);
+    }
+
+    #[test]
+    fn test_merge_duplicates_asks_first() {
+        let mut app = test_app(Config::default());
+        app.request_merge_duplicates();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("No duplicate tasks"));
+
+        create(&mut app, "Deploy");
+        create(&mut app, "deploy");
+        create(&mut app, "Other");
+        app.request_merge_duplicates();
+        assert_eq!(app.pending_confirmation, Some(ConfirmAction::MergeDuplicates(1)));
+
+        app.confirm();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.total_tasks(), 2);
+
+        app.undo();
+        assert_eq!(app.board.total_tasks(), 3);
+    }
+
+    #[test]
+    fn test_purge_archive_asks_first() {
+        let mut app = test_app(Config::default());
+        app.request_purge_archive();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("The archive is empty"));
+
+        create(&mut app, "Shipped");
+        create(&mut app, "Open");
+        app.selected_task_index = Some(0);
+        app.archive_selected_task();
+        assert_eq!(app.board.total_tasks(), 1);
+        assert_eq!(app.selected_task_index, Some(0));
+
+        app.request_purge_archive();
+        assert_eq!(app.pending_confirmation, Some(ConfirmAction::PurgeArchive(1)));
+        app.confirm();
+        assert!(app.board.archived_tasks().is_empty());
+
+        app.undo();
+        assert_eq!(app.board.archived_tasks().len(), 1);
+    }
+
+    #[test]
+    fn test_complete_tagged_asks_first() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Login form");
+        create(&mut app, "Unrelated");
+        let login = app.board.columns[0].tasks[0].id;
+        app.board.add_task_tag(0, login, "feature-x").unwrap();
+
+        app.start_completing_tag();
+        app.input_buffer = "#nothing".to_string();
+        app.request_complete_tagged();
+        assert_eq!(app.pending_confirmation, None);
+        assert_eq!(app.status_message.as_deref(), Some("No open tasks tagged 'nothing'"));
+
+        app.start_completing_tag();
+        app.input_buffer = "#feature-x".to_string();
+        app.request_complete_tagged();
+        assert_eq!(
+            app.pending_confirmation,
+            Some(ConfirmAction::CompleteTagged("feature-x".to_string(), 1))
+        );
+
+        app.confirm();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.columns[2].tasks[0].id, login);
+        assert_eq!(app.board.columns[0].tasks.len(), 1);
+        assert!(app.selected_task_id().is_some());
+
+        app.undo();
+        assert_eq!(app.board.columns[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_saving_auto_archives_over_limit() {
+        let mut app = test_app(Config::default());
+        app.board.set_auto_archive_after(0, Some(2)).unwrap();
+        for title in ["First", "Second", "Third"] {
+            create(&mut app, title);
+        }
+
+        let titles: Vec<&str> = app.board.columns[0].tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Second", "Third"]);
+        assert_eq!(app.board.archived_tasks()[0].title, "First");
+        assert!(app.selected_task_index.is_some_and(|idx| idx < 2));
+        assert_eq!(app.current_notification().unwrap().text, "Auto-archived 1 task(s)");
+
+        let saved = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(saved.archived_tasks().len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_sort_mode_sorts_and_selects_new_tasks() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Plain");
+        create(&mut app, "Urgent");
+        app.cycle_priority();
+        app.cycle_priority();
+        app.cycle_priority();
+
+        app.cycle_sort_mode();
+        assert_eq!(app.board.columns[0].sort_mode, SortMode::Priority);
+        assert_eq!(app.board.columns[0].tasks[0].title, "Urgent");
+        assert_eq!(app.selected_task_index, Some(0));
+
+        // New tasks land in sorted position and are selected there
+        create(&mut app, "Another");
+        assert_eq!(app.board.columns[0].tasks[2].title, "Another");
+        assert_eq!(app.selected_task_index, Some(2));
+
+        let loaded = app.storage.load_board(&app.current_board_name).unwrap().unwrap();
+        assert_eq!(loaded.columns[0].sort_mode, SortMode::Priority);
+    }
+
+    #[test]
+    fn test_command_palette_runs_selected_command() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Task");
+
+        app.start_command_palette();
+        for c in "move".chars() {
+            app.handle_char_input(c);
+        }
+        app.next_command();
+        assert_eq!(app.command_selection, 1);
+        app.run_selected_command();
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.input_buffer.is_empty());
+        assert_eq!(app.board.columns[1].tasks[0].title, "Task");
+
+        // Typing resets the highlight to the first match
+        app.start_command_palette();
+        app.next_command();
+        app.handle_char_input('n');
+        assert_eq!(app.command_selection, 0);
+        app.handle_backspace();
+        app.handle_char_input('q');
+        app.run_selected_command();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_command_palette_keeps_input_without_match() {
+        let mut app = test_app(Config::default());
+        app.start_command_palette();
+        for c in "bogus".chars() {
+            app.handle_char_input(c);
+        }
+        app.run_selected_command();
+        assert_eq!(app.input_mode, InputMode::CommandPalette);
+        assert_eq!(app.input_buffer, "bogus");
+        assert!(app.status_message.as_deref().unwrap().contains("bogus"));
+
+        app.cancel_command_palette();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    #[test]
+    fn test_priority_bump_follows_task_in_sorted_column() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Routine");
+        create(&mut app, "Urgent");
+        app.board.set_sort_mode(0, SortMode::Priority).unwrap();
+
+        app.selected_task_index = Some(1);
+        app.cycle_priority();
+        assert_eq!(app.board.columns[0].tasks[0].title, "Urgent");
+        assert_eq!(app.selected_task_index, Some(0));
+    }
+
+    #[test]
+    fn test_failed_save_enqueues_notification() {
+        let mut app = test_app(Config::default());
+        let blocker = env::temp_dir().join(format!("kanban-app-notify-{}", std::process::id()));
+        std::fs::write(&blocker, "not a directory").unwrap();
+        app.storage = Storage::with_path(blocker.clone());
+
+        create(&mut app, "Task");
+        let notification = app.current_notification().unwrap();
+        assert_eq!(notification.level, NotificationLevel::Error);
+        assert!(notification.text.starts_with("Save failed"));
+
+        // Notifications disappear once they've been shown long enough
+        let created = app.current_notification().unwrap().created;
+        app.expire_notifications_at(created);
+        assert_eq!(app.notifications.len(), 1);
+        app.expire_notifications_at(created + NOTIFICATION_TTL);
+        assert!(app.notifications.is_empty());
+
+        std::fs::remove_file(blocker).unwrap();
+    }
+
+    #[test]
+    fn test_switch_board_aborts_when_save_fails() {
+        let mut app = app_with_boards(&["work", "home"]);
+        assert_eq!(app.current_board_name, "home");
+        create(&mut app, "Unsaved elsewhere");
+
+        let blocker = env::temp_dir().join(format!("kanban-app-switch-{}", std::process::id()));
+        std::fs::write(&blocker, "not a directory").unwrap();
+        app.storage = Storage::with_path(blocker.clone());
+
+        app.input_buffer = "work".to_string();
+        let result = app.switch_board();
+        assert!(result.is_err());
+        assert_eq!(app.current_board_name, "home");
+        assert_eq!(app.board.columns[0].tasks[0].title, "Unsaved elsewhere");
+
+        // The selector doesn't switch either, and the failure is reported once
+        app.notifications.clear();
+        select_board(&mut app, "work");
+        app.switch_to_selected_board();
+        assert_eq!(app.current_board_name, "home");
+        assert_eq!(app.notifications.len(), 1);
+        assert!(app.current_notification().unwrap().text.starts_with("Staying on 'home'"));
+
+        std::fs::remove_file(blocker).unwrap();
+    }
+
+    #[test]
+    fn test_switching_to_the_current_board_keeps_selection() {
+        let mut app = app_with_boards(&["work"]);
+        app.selected_column = 1;
+        create(&mut app, "First");
+        create(&mut app, "Second");
+        app.selected_task_index = Some(1);
+        let previous = app.previous_board_name.clone();
+
+        // Storage that can't be written proves nothing is saved either
+        let blocker = env::temp_dir().join(format!("kanban-app-self-switch-{}", std::process::id()));
+        std::fs::write(&blocker, "not a directory").unwrap();
+        app.storage = Storage::with_path(blocker.clone());
+        app.notifications.clear();
+
+        select_board(&mut app, "work");
+        app.switch_to_selected_board();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.current_board_name, "work");
+        assert_eq!(app.previous_board_name, previous);
+        assert_eq!(app.selected_column, 1);
+        assert_eq!(app.selected_task_index, Some(1));
+        assert!(app.notifications.is_empty());
+
+        std::fs::remove_file(blocker).unwrap();
+    }
+
+    #[test]
+    fn test_quick_capture_from_normal_mode() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Existing");
+        app.selected_task_index = Some(0);
+        app.config.new_task_column = NewTaskTarget::FixedName("Done".to_string());
+
+        app.start_quick_capture();
+        assert_eq!(app.input_mode, InputMode::Capturing);
+        app.input_buffer = "Call the plumber".to_string();
+        app.finish_quick_capture();
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.board.columns[2].tasks[0].title, "Call the plumber");
+        assert_eq!(app.selected_task_index, Some(0));
+        assert_eq!(app.status_message.as_deref(), Some("Captured 'Call the plumber'"));
+        app.undo();
+        assert!(app.board.columns[2].tasks.is_empty());
+    }
+
+    #[test]
+    fn test_quick_capture_restores_viewing_mode() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Being read");
+        app.selected_task_index = Some(0);
+        app.start_viewing();
+        assert_eq!(app.input_mode, InputMode::Viewing);
+
+        app.start_quick_capture();
+        app.input_buffer = "Idea".to_string();
+        app.finish_quick_capture();
+        assert_eq!(app.input_mode, InputMode::Viewing);
+        assert_eq!(app.board.columns[0].tasks.len(), 2);
+        // The task being viewed stays selected
+        assert_eq!(app.selected_task_id(), Some(app.board.columns[0].tasks[0].id));
+
+        // Cancelling returns to a half-typed input untouched
+        app.start_creating();
+        app.input_buffer = "Draft".to_string();
+        app.start_quick_capture();
+        assert_eq!(app.input_buffer, "");
+        app.input_buffer = "Never mind".to_string();
+        app.cancel_quick_capture();
+        assert_eq!(app.input_mode, InputMode::Creating);
+        assert_eq!(app.input_buffer, "Draft");
+        assert_eq!(app.board.columns[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_selected_task_ignores_stale_selection() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Only");
+        app.selected_task_index = Some(0);
+        assert_eq!(app.selected_task().map(|t| t.title.as_str()), Some("Only"));
+        assert_eq!(app.selected_task_id(), Some(app.board.columns[0].tasks[0].id));
+
+        // Out of range indices select nothing, so task actions do nothing
+        for (column, index) in [(0, 5), (9, 0)] {
+            app.selected_column = column;
+            app.selected_task_index = Some(index);
+            assert!(app.selected_task().is_none());
+            app.delete_selected_task();
+            app.move_task_right();
+            app.start_editing();
+            assert_eq!(app.input_mode, InputMode::Normal);
+            assert_eq!(app.board.columns[0].tasks.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_starts_in_selector() {
+        let enabled = Config { start_in_selector: true, ..Config::default() };
+        let disabled = Config::default();
+
+        for (config, board_count, requested, expected) in [
+            (&enabled, 0, false, false),
+            (&enabled, 1, false, false),
+            (&enabled, 2, false, true),
+            (&enabled, 5, false, true),
+            (&enabled, 5, true, false),
+            (&disabled, 1, false, false),
+            (&disabled, 5, false, false),
+        ] {
+            assert_eq!(
+                starts_in_selector(config, board_count, requested),
+                expected,
+                "{} boards, requested: {}",
+                board_count,
+                requested
+            );
+        }
+    }
+
+    #[test]
+    fn test_toggle_done_moves_to_done_and_back() {
+        let mut app = test_app(Config::default());
+        create(&mut app, "Ship it");
+        create(&mut app, "Other");
+        app.selected_task_index = Some(0);
+        let task_id = app.selected_task_id().unwrap();
+
+        app.toggle_selected_done();
+        assert_eq!(app.selected_column, 2);
+        assert_eq!(app.selected_task_id(), Some(task_id));
+        assert_eq!(app.board.columns[0].tasks.len(), 1);
+
+        // Pressing again on a done task reopens it in the first column
+        app.toggle_selected_done();
+        assert_eq!(app.selected_column, 0);
+        assert_eq!(app.selected_task_id(), Some(task_id));
+        assert!(app.board.columns[2].tasks.is_empty());
+
+        app.undo();
+        assert_eq!(app.board.columns[2].tasks[0].id, task_id);
+    }
+
+    #[test]
+    fn test_toggle_done_follows_done_column_detection() {
+        let mut app = test_app(Config::default());
+        app.board.columns[1].name = "Done".to_string();
+        app.board.columns[2].name = "Released".to_string();
+        create(&mut app, "Task");
+        app.selected_task_index = Some(0);
+
+        app.toggle_selected_done();
+        assert_eq!(app.selected_column, 1);
+        assert_eq!(app.board.columns[1].tasks[0].title, "Task");
+
+        app.board.done_column_names = vec!["Shipped".to_string()];
+        app.selected_column = 0;
+        create(&mut app, "Nowhere to go");
+        app.selected_task_index = Some(0);
+        app.toggle_selected_done();
+        assert_eq!(app.status_message.as_deref(), Some("This board has no done column"));
+        assert_eq!(app.selected_column, 0);
+    }
+
+    #[test]
+    fn test_create_task_in_out_of_range_column_reports_error() {
+        let mut app = test_app(Config::default());
+        app.selected_column = 7;
+
+        create(&mut app, "Important");
+        assert!(app.status_message.as_deref().unwrap().contains("Could not create task"));
+        assert_eq!(app.input_mode, InputMode::Creating);
+        assert_eq!(app.input_buffer, "Important");
+        assert_eq!(app.selected_column, 2);
+        assert!(app.undo_stack.is_empty());
+
+        // Submitting again lands the task in the clamped column
+        app.create_task();
+        assert_eq!(app.board.columns[2].tasks[0].title, "Important");
+    }
+
+    #[test]
+    fn test_new_task_column_selected() {
+        let mut app = test_app(Config::default());
+        app.selected_column = 1;
+        create(&mut app, "Here");
+        assert_eq!(app.board.columns[1].tasks[0].title, "Here");
+        assert_eq!(app.selected_task_index, Some(0));
+    }
+
+    #[test]
+    fn test_new_task_column_fixed_index() {
+        let mut app = test_app(Config {
+            new_task_column: NewTaskTarget::FixedIndex(0),
+            ..Config::default()
+        });
+        app.selected_column = 2;
+        create(&mut app, "Inbox");
+        assert_eq!(app.board.columns[0].tasks[0].title, "Inbox");
+        assert_eq!(app.selected_column, 2);
+        assert!(app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_new_task_column_fixed_name() {
+        let mut app = test_app(Config {
+            new_task_column: NewTaskTarget::FixedName("In Progress".to_string()),
+            ..Config::default()
+        });
+        create(&mut app, "Started");
+        assert_eq!(app.board.columns[1].tasks[0].title, "Started");
+    }
+
+    #[test]
+    fn test_new_task_column_unknown_falls_back_with_warning() {
+        for target in [NewTaskTarget::FixedIndex(9), NewTaskTarget::FixedName("Inbox".to_string())] {
+            let mut app = test_app(Config { new_task_column: target, ..Config::default() });
+            app.selected_column = 2;
+            create(&mut app, "Fallback");
+            assert_eq!(app.board.columns[2].tasks[0].title, "Fallback");
+            assert!(app.status_message.as_deref().unwrap().contains("not found"));
+        }
+    }
 }
@@ -1,7 +1,10 @@
 //! Board type for managing Kanban columns and tasks.
 
-use crate::{Column, Task};
+use crate::clock;
+use crate::stable_id;
+use crate::{BoardView, Column, ColumnView, Priority, SortMode, Task, TaskView};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Represents a Kanban board with multiple columns.
 ///
@@ -28,11 +31,41 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Board {
     pub name: String,
+    /// Free-form context for the board, e.g. "Q3 marketing tasks"; empty by default
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
     pub columns: Vec<Column>,
     next_task_id: usize,
+    /// Index of the column that counts as "done" (defaults to the last column)
+    #[serde(default)]
+    done_column: Option<usize>,
+    /// Names of the columns that count as "done", matched ignoring case.
+    ///
+    /// Unlike an index this survives reordering columns. An empty list (as on
+    /// boards saved before it existed) makes the last column the done column.
+    #[serde(default)]
+    pub done_column_names: Vec<String>,
+    /// Custom priority level names, highest first; empty uses the built-in [`Priority`] levels
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    priority_scheme: Vec<String>,
+    /// Recently entered task titles, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    title_history: Vec<String>,
+    /// Tasks taken off the board but kept for reference, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    archived: Vec<Task>,
+    /// Whether only the selected column is drawn, across the full width
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub focus_mode: bool,
+    /// Whether tasks carry a stable [`Task::uuid`] (see [`Board::enable_stable_ids`])
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    stable_ids: bool,
 }
 
 impl Board {
+    /// Maximum number of recently entered titles remembered per board
+    pub const TITLE_HISTORY_LIMIT: usize = 50;
+
     /// Creates a new board with default columns (To Do, In Progress, Done).
     ///
     /// # Examples
@@ -48,23 +81,108 @@ impl Board {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
+            description: String::new(),
             columns: vec![
                 Column::new("To Do"),
                 Column::new("In Progress"),
                 Column::new("Done"),
             ],
             next_task_id: 1,
+            done_column: None,
+            done_column_names: vec!["Done".to_string()],
+            priority_scheme: Vec::new(),
+            title_history: Vec::new(),
+            archived: Vec::new(),
+            focus_mode: false,
+            stable_ids: false,
         }
     }
 
-    /// Creates a new board with custom columns
-    pub fn with_columns(name: impl Into<String>, column_names: Vec<String>) -> Self {
+    /// Creates a new board with custom columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no columns are given or if two columns share a name,
+    /// since name-based lookups need unique names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let board = Board::with_columns("Flow", vec!["Backlog".to_string(), "Done".to_string()]).unwrap();
+    /// assert_eq!(board.columns.len(), 2);
+    ///
+    /// assert!(Board::with_columns("Empty", Vec::new()).is_err());
+    /// assert!(Board::with_columns("Dupes", vec!["A".to_string(), "A".to_string()]).is_err());
+    /// ```
+    pub fn with_columns(name: impl Into<String>, column_names: Vec<String>) -> Result<Self, String> {
+        if column_names.is_empty() {
+            return Err("A board needs at least one column".to_string());
+        }
+        for (idx, column_name) in column_names.iter().enumerate() {
+            if column_names[..idx].contains(column_name) {
+                return Err(format!("Duplicate column name '{}'", column_name));
+            }
+        }
+
         let columns = column_names.into_iter().map(Column::new).collect();
-        Self {
+        Ok(Self {
             name: name.into(),
+            description: String::new(),
             columns,
             next_task_id: 1,
+            done_column: None,
+            done_column_names: Vec::new(),
+            priority_scheme: Vec::new(),
+            title_history: Vec::new(),
+            archived: Vec::new(),
+            focus_mode: false,
+            stable_ids: false,
+        })
+    }
+
+    /// Copies the board under a new name, e.g. to start a new board from a template.
+    ///
+    /// With `include_tasks` the copy keeps every task (with its id) and the
+    /// archive, and on boards with stable ids each copied task gets a fresh
+    /// uuid so the two boards don't share them. Without it only the structure
+    /// is kept: columns with their settings, done columns, priority scheme and
+    /// description, with task ids starting over from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Sprint 1");
+    /// board.add_task(0, "Carry over").unwrap();
+    ///
+    /// let next = board.clone_as("Sprint 2", false);
+    /// assert_eq!(next.name, "Sprint 2");
+    /// assert_eq!(next.columns.len(), 3);
+    /// assert!(next.columns[0].tasks.is_empty());
+    ///
+    /// let copy = board.clone_as("Sprint 1 copy", true);
+    /// assert_eq!(copy.columns[0].tasks[0].title, "Carry over");
+    /// ```
+    pub fn clone_as(&self, new_name: impl Into<String>, include_tasks: bool) -> Self {
+        let mut board = self.clone();
+        board.name = new_name.into();
+        if !include_tasks {
+            for column in &mut board.columns {
+                column.tasks.clear();
+            }
+            board.archived.clear();
+            board.title_history.clear();
+            board.next_task_id = 1;
+        } else if board.stable_ids {
+            let tasks = board.columns.iter_mut().flat_map(|column| column.tasks.iter_mut());
+            for task in tasks.chain(board.archived.iter_mut()) {
+                task.uuid = Some(stable_id::new_uuid());
+            }
         }
+        board
     }
 
     /// Adds a new task to the specified column.
@@ -98,12 +216,39 @@ impl Board {
         let task_id = self.next_task_id;
         self.next_task_id += 1;
 
-        let task = Task::new(task_id, title);
+        let mut task = Task::new(task_id, title);
+        if self.stable_ids {
+            task.uuid = Some(stable_id::new_uuid());
+        }
+        task.enter_column(self.columns[column_index].name.clone());
         self.columns[column_index].add_task(task);
 
         Ok(task_id)
     }
 
+    /// Adds a copy of an existing task (e.g. from another board) to a column.
+    ///
+    /// The copy gets a fresh id (and, with stable ids enabled, a fresh uuid)
+    /// from this board and starts its column history in the destination
+    /// column. Returns the new task's id.
+    pub fn insert_task_copy(&mut self, column_index: usize, task: &Task) -> Result<usize, String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
+
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let mut copy = task.clone();
+        copy.id = task_id;
+        copy.uuid = self.stable_ids.then(stable_id::new_uuid);
+        copy.column_history.clear();
+        copy.enter_column(self.columns[column_index].name.clone());
+        self.columns[column_index].add_task(copy);
+
+        Ok(task_id)
+    }
+
     /// Moves a task from one column to another.
     ///
     /// # Errors
@@ -130,38 +275,121 @@ impl Board {
         from_column: usize,
         to_column: usize,
         task_id: usize,
+    ) -> Result<(), String> {
+        self.move_task_to(from_column, to_column, task_id, usize::MAX)
+    }
+
+    /// Moves a task to position `dest_index` in another column.
+    ///
+    /// The index is clamped to the end of the destination column, and columns
+    /// with a sort mode other than manual still place the task by their sort.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Either column index is out of bounds
+    /// - The task is not found in the source column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(1, "Existing").unwrap();
+    /// let task_id = board.add_task(0, "Urgent").unwrap();
+    ///
+    /// board.move_task_to(0, 1, task_id, 0).unwrap();
+    /// assert_eq!(board.columns[1].tasks[0].title, "Urgent");
+    /// ```
+    pub fn move_task_to(
+        &mut self,
+        from_column: usize,
+        to_column: usize,
+        task_id: usize,
+        dest_index: usize,
     ) -> Result<(), String> {
         if from_column >= self.columns.len() || to_column >= self.columns.len() {
             return Err("Column index out of bounds".to_string());
         }
 
-        let task = self.columns[from_column]
+        let mut task = self.columns[from_column]
             .remove_task(task_id)
             .ok_or("Task not found in source column")?;
 
-        self.columns[to_column].add_task(task);
+        // Reordering within a column doesn't start a new stay in it
+        if to_column != from_column {
+            task.enter_column(self.columns[to_column].name.clone());
+        }
+        self.columns[to_column].insert_task(dest_index, task);
         Ok(())
     }
 
-    /// Updates the title of a task in a specified column
+    /// Moves a task to the column with the given name, wherever it currently is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task doesn't exist or no column has that name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let task_id = board.add_task(0, "Ship it").unwrap();
+    ///
+    /// board.move_task_to_column_by_name(task_id, "Done").unwrap();
+    /// assert_eq!(board.columns[2].tasks.len(), 1);
+    /// assert!(board.move_task_to_column_by_name(task_id, "Nowhere").is_err());
+    /// ```
+    pub fn move_task_to_column_by_name(
+        &mut self,
+        task_id: usize,
+        to_column_name: &str,
+    ) -> Result<(), String> {
+        let (_, from_column) = self
+            .get_task(task_id)
+            .ok_or_else(|| format!("Task {} not found", task_id))?;
+        let to_column = self
+            .column_index_by_name(to_column_name)
+            .ok_or_else(|| format!("Column '{}' not found", to_column_name))?;
+
+        self.move_task(from_column, to_column, task_id)
+    }
+
+    /// Updates the title of a task in a specified column, returning the
+    /// previous title so callers can log or undo the change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let task_id = board.add_task(0, "Draft").unwrap();
+    ///
+    /// let old = board.update_task_title(0, task_id, "Final").unwrap();
+    /// assert_eq!(old, "Draft");
+    /// assert_eq!(board.columns[0].tasks[0].title, "Final");
+    /// ```
     pub fn update_task_title(
         &mut self,
         column_index: usize,
         task_id: usize,
         new_title: impl Into<String>,
-    ) -> Result<(), String> {
+    ) -> Result<String, String> {
         if column_index >= self.columns.len() {
             return Err("Column index out of bounds".to_string());
         }
 
         let task = self.columns[column_index]
-            .tasks
-            .iter_mut()
-            .find(|t| t.id == task_id)
+            .task_by_id_mut(task_id)
             .ok_or("Task not found in column")?;
 
+        let old_title = task.title.clone();
         task.update_title(new_title);
-        Ok(())
+        Ok(old_title)
     }
 
     /// Updates the description of a task in a specified column
@@ -176,16 +404,17 @@ impl Board {
         }
 
         let task = self.columns[column_index]
-            .tasks
-            .iter_mut()
-            .find(|t| t.id == task_id)
+            .task_by_id_mut(task_id)
             .ok_or("Task not found in column")?;
 
         task.set_description(description);
         Ok(())
     }
 
-    /// Cycles the priority of a task in a specified column
+    /// Cycles the priority of a task in a specified column.
+    ///
+    /// With a custom priority scheme this steps up through the scheme's levels,
+    /// wrapping from the highest back to the lowest, just like the built-in levels.
     pub fn cycle_task_priority(
         &mut self,
         column_index: usize,
@@ -195,16 +424,199 @@ impl Board {
             return Err("Column index out of bounds".to_string());
         }
 
+        let level_count = self.priority_scheme.len();
         let task = self.columns[column_index]
-            .tasks
-            .iter_mut()
-            .find(|t| t.id == task_id)
+            .task_by_id_mut(task_id)
+            .ok_or("Task not found in column")?;
+
+        if level_count == 0 {
+            task.cycle_priority();
+        } else {
+            let level = Self::level_in_scheme(task, level_count);
+            let next = if level == 0 { level_count - 1 } else { level - 1 };
+            task.set_priority(Priority::for_level(next, level_count));
+            task.priority_level = Some(next);
+        }
+        // A priority-sorted column moves the task to its new rank straight away
+        self.columns[column_index].apply_sort();
+        Ok(())
+    }
+
+    /// Sets the priority of a task in a specified column.
+    ///
+    /// Like [`Board::cycle_task_priority`], this repositions the task when the
+    /// column is sorted by priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Board, Priority, SortMode};
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.set_sort_mode(0, SortMode::Priority).unwrap();
+    /// board.add_task(0, "Routine").unwrap();
+    /// let urgent = board.add_task(0, "Urgent").unwrap();
+    ///
+    /// board.set_task_priority(0, urgent, Priority::High).unwrap();
+    /// assert_eq!(board.columns[0].tasks[0].title, "Urgent");
+    /// ```
+    pub fn set_task_priority(
+        &mut self,
+        column_index: usize,
+        task_id: usize,
+        priority: Priority,
+    ) -> Result<(), String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
+
+        let task = self.columns[column_index]
+            .task_by_id_mut(task_id)
             .ok_or("Task not found in column")?;
 
-        task.cycle_priority();
+        task.set_priority(priority);
+        self.columns[column_index].apply_sort();
         Ok(())
     }
 
+    /// Sets the priority of every task in `task_ids`, wherever it is, skipping
+    /// unknown ids. Returns how many tasks were updated.
+    ///
+    /// Priority-sorted columns are re-sorted afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Board, Priority};
+    ///
+    /// let mut board = Board::new("Project");
+    /// let a = board.add_task(0, "Bug A").unwrap();
+    /// let b = board.add_task(1, "Bug B").unwrap();
+    ///
+    /// assert_eq!(board.set_priority_for(&[a, b, 99], Priority::High), 2);
+    /// assert_eq!(board.columns[1].tasks[0].priority, Priority::High);
+    /// ```
+    pub fn set_priority_for(&mut self, task_ids: &[usize], priority: Priority) -> usize {
+        let mut updated = 0;
+        for &task_id in task_ids {
+            if let Some(task) = self.task_mut(task_id) {
+                task.set_priority(priority);
+                updated += 1;
+            }
+        }
+        if updated > 0 {
+            self.apply_sort_modes();
+        }
+        updated
+    }
+
+    /// Returns the priority level names in use, highest first.
+    ///
+    /// Boards without a custom scheme use the built-in levels.
+    pub fn priority_scheme(&self) -> Vec<String> {
+        if self.priority_scheme.is_empty() {
+            Priority::all().iter().map(|p| p.to_string()).collect()
+        } else {
+            self.priority_scheme.clone()
+        }
+    }
+
+    /// Replaces the priority scheme with custom level names (highest first).
+    ///
+    /// Existing tasks are migrated: tasks keep their level if it still exists,
+    /// otherwise their built-in priority maps to the same position in the new
+    /// scheme (clamped to its lowest level). Passing an empty list restores the
+    /// built-in levels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Board, Priority};
+    ///
+    /// let mut board = Board::new("Team".to_string());
+    /// let id = board.add_task(0, "Outage".to_string()).unwrap();
+    /// board.columns[0].tasks[0].set_priority(Priority::High);
+    ///
+    /// let levels = ["P0", "P1", "P2", "P3", "P4"].map(String::from).to_vec();
+    /// board.set_priority_scheme(levels);
+    /// assert_eq!(board.priority_label(board.get_task(id).unwrap().0), "P0");
+    ///
+    /// board.cycle_task_priority(0, id).unwrap();
+    /// assert_eq!(board.priority_label(board.get_task(id).unwrap().0), "P4");
+    /// ```
+    pub fn set_priority_scheme(&mut self, levels: Vec<String>) {
+        let level_count = levels.len();
+        for task in self.columns.iter_mut().flat_map(|c| c.tasks.iter_mut()) {
+            if level_count == 0 {
+                task.priority_level = None;
+            } else {
+                let level = Self::level_in_scheme(task, level_count);
+                task.priority = Priority::for_level(level, level_count);
+                task.priority_level = Some(level);
+            }
+        }
+        self.priority_scheme = levels;
+    }
+
+    /// Sets the board description, trimming surrounding whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Marketing");
+    /// board.set_description("  Q3 marketing tasks ");
+    /// assert_eq!(board.description, "Q3 marketing tasks");
+    /// ```
+    pub fn set_description(&mut self, description: impl Into<String>) {
+        self.description = description.into().trim().to_string();
+    }
+
+    /// Remembers a newly entered task title for later recall.
+    ///
+    /// Blank titles and repeats of the most recent entry are ignored; the
+    /// oldest entries are dropped once [`Board::TITLE_HISTORY_LIMIT`] is reached.
+    pub fn record_title(&mut self, title: &str) {
+        let title = title.trim();
+        if title.is_empty() || self.title_history.last().map(String::as_str) == Some(title) {
+            return;
+        }
+        self.title_history.push(title.to_string());
+        if self.title_history.len() > Self::TITLE_HISTORY_LIMIT {
+            let excess = self.title_history.len() - Self::TITLE_HISTORY_LIMIT;
+            self.title_history.drain(..excess);
+        }
+    }
+
+    /// Returns recently entered task titles, oldest first
+    pub fn title_history(&self) -> &[String] {
+        &self.title_history
+    }
+
+    /// Returns true if the board uses custom priority level names
+    pub fn has_custom_priority_scheme(&self) -> bool {
+        !self.priority_scheme.is_empty()
+    }
+
+    /// Display name of a task's priority under this board's scheme
+    pub fn priority_label(&self, task: &Task) -> String {
+        if self.priority_scheme.is_empty() {
+            task.priority.to_string()
+        } else {
+            let level = Self::level_in_scheme(task, self.priority_scheme.len());
+            self.priority_scheme[level].clone()
+        }
+    }
+
+    /// A task's level within an `n`-level scheme, derived from its built-in
+    /// priority when it has no explicit level
+    fn level_in_scheme(task: &Task, level_count: usize) -> usize {
+        task.priority_level
+            .unwrap_or_else(|| task.priority.to_level(level_count))
+            .min(level_count - 1)
+    }
+
     /// Adds a tag to a task in a specified column
     pub fn add_task_tag(
         &mut self,
@@ -217,122 +629,2395 @@ impl Board {
         }
 
         let task = self.columns[column_index]
-            .tasks
-            .iter_mut()
-            .find(|t| t.id == task_id)
+            .task_by_id_mut(task_id)
             .ok_or("Task not found in column")?;
 
         task.add_tag(tag);
         Ok(())
     }
 
-    /// Sets the due date of a task in a specified column
-    pub fn set_task_due_date(
+    /// Adds a watcher to a task in a specified column
+    pub fn add_task_watcher(
         &mut self,
         column_index: usize,
         task_id: usize,
-        due_date: Option<String>,
+        watcher: impl Into<String>,
     ) -> Result<(), String> {
         if column_index >= self.columns.len() {
             return Err("Column index out of bounds".to_string());
         }
 
         let task = self.columns[column_index]
-            .tasks
-            .iter_mut()
-            .find(|t| t.id == task_id)
+            .task_by_id_mut(task_id)
             .ok_or("Task not found in column")?;
 
-        task.set_due_date(due_date);
+        task.add_watcher(watcher);
         Ok(())
     }
 
-    /// Gets a reference to a task by ID, searching all columns
-    pub fn get_task(&self, task_id: usize) -> Option<(&Task, usize)> {
-        for (col_idx, column) in self.columns.iter().enumerate() {
-            if let Some(task) = column.tasks.iter().find(|t| t.id == task_id) {
-                return Some((task, col_idx));
-            }
+    /// Removes a watcher from a task in a specified column
+    pub fn remove_task_watcher(
+        &mut self,
+        column_index: usize,
+        task_id: usize,
+        watcher: &str,
+    ) -> Result<(), String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
         }
-        None
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let task = self.columns[column_index]
+            .task_by_id_mut(task_id)
+            .ok_or("Task not found in column")?;
 
-    #[test]
-    fn test_board_creation() {
-        let board = Board::new("My Board");
-        assert_eq!(board.name, "My Board");
-        assert_eq!(board.columns.len(), 3);
-        assert_eq!(board.columns[0].name, "To Do");
-        assert_eq!(board.columns[1].name, "In Progress");
-        assert_eq!(board.columns[2].name, "Done");
+        task.remove_watcher(watcher);
+        Ok(())
     }
 
-    #[test]
-    fn test_board_add_task() {
-        let mut board = Board::new("Test");
-        let result = board.add_task(0, "New task");
+    /// Adds a tag to every task in a column.
+    ///
+    /// Returns how many tasks gained the tag; tasks that already carry it are
+    /// skipped, so re-running is a no-op. Empty tags are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project".to_string());
+    /// board.add_task(0, "One".to_string()).unwrap();
+    /// board.add_task(0, "Two".to_string()).unwrap();
+    ///
+    /// assert_eq!(board.tag_all_in_column(0, "sprint-1").unwrap(), 2);
+    /// assert_eq!(board.tag_all_in_column(0, "sprint-1").unwrap(), 0);
+    /// ```
+    pub fn tag_all_in_column(&mut self, column_index: usize, tag: &str) -> Result<usize, String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
 
-        assert!(result.is_ok());
-        assert_eq!(board.columns[0].tasks.len(), 1);
-        assert_eq!(board.columns[0].tasks[0].title, "New task");
+        let mut count = 0;
+        for task in &mut self.columns[column_index].tasks {
+            let before = task.tags.len();
+            task.add_tag(tag);
+            if task.tags.len() > before {
+                count += 1;
+            }
+        }
+        Ok(count)
     }
 
-    #[test]
-    fn test_board_move_task() {
-        let mut board = Board::new("Test");
-        let task_id = board.add_task(0, "Task to move").unwrap();
+    /// Removes a tag from every task in a column, returning how many tasks lost it
+    pub fn untag_all_in_column(&mut self, column_index: usize, tag: &str) -> Result<usize, String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
 
-        let result = board.move_task(0, 1, task_id);
-        assert!(result.is_ok());
-        assert_eq!(board.columns[0].tasks.len(), 0);
-        assert_eq!(board.columns[1].tasks.len(), 1);
-        assert_eq!(board.columns[1].tasks[0].title, "Task to move");
+        let mut count = 0;
+        for task in &mut self.columns[column_index].tasks {
+            let before = task.tags.len();
+            task.remove_tag(tag);
+            if task.tags.len() < before {
+                count += 1;
+            }
+        }
+        Ok(count)
     }
 
-    #[test]
-    fn test_board_move_task_invalid_column() {
-        let mut board = Board::new("Test");
-        let task_id = board.add_task(0, "Task").unwrap();
-
-        let result = board.move_task(0, 10, task_id);
+    /// Sets the due date of a task in a specified column
+    pub fn set_task_due_date(
+        &mut self,
+        column_index: usize,
+        task_id: usize,
+        due_date: Option<String>,
+    ) -> Result<(), String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
+
+        let task = self.columns[column_index]
+            .task_by_id_mut(task_id)
+            .ok_or("Task not found in column")?;
+
+        task.set_due_date(due_date);
+        Ok(())
+    }
+
+    /// Sets or, with `None`, clears the start date of a task in a column
+    pub fn set_task_start_date(
+        &mut self,
+        column_index: usize,
+        task_id: usize,
+        start_date: Option<String>,
+    ) -> Result<(), String> {
+        if column_index >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
+
+        let task = self.columns[column_index]
+            .task_by_id_mut(task_id)
+            .ok_or("Task not found in column")?;
+
+        task.set_start_date(start_date);
+        Ok(())
+    }
+
+    /// Returns the task at a position within a column, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(1, "First").unwrap();
+    /// let second = board.add_task(1, "Second").unwrap();
+    ///
+    /// assert_eq!(board.task_id_at(1, 1), Some(second));
+    /// assert_eq!(board.task_at(1, 0).map(|t| t.title.as_str()), Some("First"));
+    /// assert!(board.task_at(1, 2).is_none());
+    /// assert!(board.task_at(7, 0).is_none());
+    /// ```
+    pub fn task_at(&self, column_index: usize, task_index: usize) -> Option<&Task> {
+        self.columns.get(column_index)?.tasks.get(task_index)
+    }
+
+    /// Returns the id of the task at a position within a column, if there is one
+    pub fn task_id_at(&self, column_index: usize, task_index: usize) -> Option<usize> {
+        self.task_at(column_index, task_index).map(|task| task.id)
+    }
+
+    /// Gets a reference to a task by ID, searching all columns
+    pub fn get_task(&self, task_id: usize) -> Option<(&Task, usize)> {
+        for (col_idx, column) in self.columns.iter().enumerate() {
+            if let Some(task) = column.task_by_id(task_id) {
+                return Some((task, col_idx));
+            }
+        }
+        None
+    }
+
+    /// Finds tasks whose title or tags fuzzily match the query.
+    ///
+    /// Returns `(column_index, task_index, score)` for every match, best match
+    /// first. Use [`Task::matches`] when an exact substring match is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(0, "Fix login").unwrap();
+    /// board.add_task(1, "Write docs").unwrap();
+    ///
+    /// let results = board.fuzzy_search("flgn");
+    /// assert_eq!(results.len(), 1);
+    /// assert_eq!((results[0].0, results[0].1), (0, 0));
+    /// ```
+    pub fn fuzzy_search(&self, query: &str) -> Vec<(usize, usize, i64)> {
+        let mut results: Vec<(usize, usize, i64)> = self
+            .columns
+            .iter()
+            .enumerate()
+            .flat_map(|(col_idx, column)| {
+                column.tasks.iter().enumerate().filter_map(move |(task_idx, task)| {
+                    task.fuzzy_score(query).map(|score| (col_idx, task_idx, score))
+                })
+            })
+            .collect();
+
+        // Stable sort keeps board order among equal scores
+        results.sort_by_key(|&(_, _, score)| std::cmp::Reverse(score));
+        results
+    }
+
+    /// Groups the ids of tasks that share a title, ignoring case and surrounding
+    /// whitespace.
+    ///
+    /// Only titles used more than once are returned. Groups and the ids within
+    /// them follow board order (column by column, top to bottom).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let first = board.add_task(0, "Fix login").unwrap();
+    /// board.add_task(0, "Write docs").unwrap();
+    /// let second = board.add_task(2, " fix LOGIN ").unwrap();
+    ///
+    /// assert_eq!(board.find_duplicates(), vec![vec![first, second]]);
+    /// ```
+    pub fn find_duplicates(&self) -> Vec<Vec<usize>> {
+        // Groups in order of first appearance, found by normalized title
+        let mut group_of: HashMap<String, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for task in self.columns.iter().flat_map(|column| &column.tasks) {
+            let key = task.title.trim().to_lowercase();
+            let group = *group_of.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(task.id);
+        }
+
+        groups.into_iter().filter(|ids| ids.len() > 1).collect()
+    }
+
+    /// Merges duplicate tasks (see [`Board::find_duplicates`]).
+    ///
+    /// The first task of each group is kept and gains the tags of the others,
+    /// which are removed. Returns how many tasks were removed.
+    pub fn dedupe(&mut self) -> usize {
+        let mut removed = 0;
+        for group in self.find_duplicates() {
+            let (keep, rest) = group.split_first().expect("duplicate groups have two or more ids");
+
+            let mut tags = Vec::new();
+            for &task_id in rest {
+                for column in &mut self.columns {
+                    if let Some(task) = column.remove_task(task_id) {
+                        tags.extend(task.tags);
+                        removed += 1;
+                        break;
+                    }
+                }
+            }
+
+            if let Some(task) = self.task_mut(*keep) {
+                for tag in tags {
+                    task.add_tag(tag);
+                }
+            }
+        }
+        removed
+    }
+
+    /// Opts the board into stable task ids.
+    ///
+    /// Every task gets a [`Task::uuid`] that, unlike the numeric id, stays the
+    /// same when the task is merged into another board. Tasks without one
+    /// (e.g. from boards saved before the switch) are assigned one now, and
+    /// new tasks get one as they are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let id = board.add_task(0, "Old task").unwrap();
+    /// assert!(board.get_task(id).unwrap().0.uuid.is_none());
+    ///
+    /// board.enable_stable_ids();
+    /// let uuid = board.get_task(id).unwrap().0.uuid.clone().unwrap();
+    /// assert_eq!(board.find_by_uuid(&uuid).unwrap().0.id, id);
+    /// ```
+    pub fn enable_stable_ids(&mut self) {
+        self.stable_ids = true;
+        self.assign_missing_uuids();
+    }
+
+    /// Whether tasks on this board carry stable uuids
+    pub fn has_stable_ids(&self) -> bool {
+        self.stable_ids
+    }
+
+    /// Gives every task without a uuid a fresh one.
+    ///
+    /// Does nothing unless stable ids are enabled; storage calls this on load
+    /// so older boards pick up uuids lazily. Returns how many were assigned.
+    pub fn assign_missing_uuids(&mut self) -> usize {
+        if !self.stable_ids {
+            return 0;
+        }
+        let mut assigned = 0;
+        for task in self.columns.iter_mut().flat_map(|column| column.tasks.iter_mut()) {
+            if task.uuid.is_none() {
+                task.uuid = Some(stable_id::new_uuid());
+                assigned += 1;
+            }
+        }
+        assigned
+    }
+
+    /// Finds a task by its stable uuid, returning it with its column index
+    pub fn find_by_uuid(&self, uuid: &str) -> Option<(&Task, usize)> {
+        self.columns.iter().enumerate().find_map(|(col_idx, column)| {
+            column
+                .tasks
+                .iter()
+                .find(|task| task.uuid.as_deref() == Some(uuid))
+                .map(|task| (task, col_idx))
+        })
+    }
+
+    /// Copies the tasks of another board into this one.
+    ///
+    /// Tasks land in the column with the same name, or the first column if
+    /// there is none, and get fresh numeric ids. They keep their uuid, and
+    /// tasks whose uuid is already on this board are skipped, so merging the
+    /// same board twice imports nothing the second time. Tasks without a uuid
+    /// are always imported and, with stable ids enabled, given one. Returns
+    /// how many tasks were imported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut ours = Board::new("Ours");
+    /// ours.enable_stable_ids();
+    /// let mut theirs = Board::new("Theirs");
+    /// theirs.enable_stable_ids();
+    /// theirs.add_task(1, "Review PR").unwrap();
+    ///
+    /// assert_eq!(ours.merge(&theirs), 1);
+    /// assert_eq!(ours.columns[1].tasks[0].title, "Review PR");
+    /// assert_eq!(ours.merge(&theirs), 0);
+    /// ```
+    pub fn merge(&mut self, other: &Board) -> usize {
+        let mut imported = 0;
+        for column in &other.columns {
+            let target = self.column_index_by_name(&column.name).unwrap_or(0);
+            for task in &column.tasks {
+                if let Some(uuid) = &task.uuid {
+                    if self.find_by_uuid(uuid).is_some() {
+                        continue;
+                    }
+                }
+
+                let task_id = self.next_task_id;
+                self.next_task_id += 1;
+
+                let mut copy = task.clone();
+                copy.id = task_id;
+                if copy.uuid.is_none() && self.stable_ids {
+                    copy.uuid = Some(stable_id::new_uuid());
+                }
+                let target_name = &self.columns[target].name;
+                if copy.column_history.last().map(|(name, _)| name) != Some(target_name) {
+                    copy.enter_column(target_name.clone());
+                }
+                self.columns[target].add_task(copy);
+                imported += 1;
+            }
+        }
+        imported
+    }
+
+    /// Moves a task off the board into its archive, stamping when it was archived.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column index is out of bounds or the task isn't in that column.
+    pub fn archive_task(&mut self, column_index: usize, task_id: usize) -> Result<(), String> {
+        let column = self
+            .columns
+            .get_mut(column_index)
+            .ok_or_else(|| "Column index out of bounds".to_string())?;
+        let mut task = column
+            .remove_task(task_id)
+            .ok_or_else(|| format!("Task {} not found", task_id))?;
+        task.mark_archived();
+        self.archived.push(task);
+        Ok(())
+    }
+
+    /// Sets or clears how many tasks a column keeps before its oldest are auto-archived
+    pub fn set_auto_archive_after(&mut self, column_index: usize, limit: Option<usize>) -> Result<(), String> {
+        let column = self
+            .columns
+            .get_mut(column_index)
+            .ok_or("Column index out of bounds")?;
+        column.auto_archive_after = limit;
+        Ok(())
+    }
+
+    /// Archives the oldest tasks of every column holding more than its
+    /// `auto_archive_after` limit, returning how many were archived.
+    ///
+    /// Age goes by creation time, ties by id; tasks whose creation time can't
+    /// be read count as newest, as in [`SortMode::Age`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.set_auto_archive_after(2, Some(2)).unwrap();
+    /// for title in ["First", "Second", "Third"] {
+    ///     board.add_task(2, title).unwrap();
+    /// }
+    ///
+    /// assert_eq!(board.auto_archive(), 1);
+    /// assert_eq!(board.archived_tasks()[0].title, "First");
+    /// assert_eq!(board.columns[2].tasks.len(), 2);
+    /// ```
+    pub fn auto_archive(&mut self) -> usize {
+        let mut archived = 0;
+        for column_index in 0..self.columns.len() {
+            let column = &self.columns[column_index];
+            let Some(limit) = column.auto_archive_after else {
+                continue;
+            };
+            let excess = column.tasks.len().saturating_sub(limit);
+            if excess == 0 {
+                continue;
+            }
+
+            let mut by_age: Vec<_> = column
+                .tasks
+                .iter()
+                .map(|task| {
+                    let created = task.created_time();
+                    (created.is_none(), created, task.id)
+                })
+                .collect();
+            by_age.sort();
+            for (_, _, task_id) in by_age.into_iter().take(excess) {
+                if self.archive_task(column_index, task_id).is_ok() {
+                    archived += 1;
+                }
+            }
+        }
+        archived
+    }
+
+    /// Tasks in the archive, oldest first
+    pub fn archived_tasks(&self) -> &[Task] {
+        &self.archived
+    }
+
+    /// Permanently removes every archived task, returning how many were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let id = board.add_task(2, "Shipped").unwrap();
+    /// board.archive_task(2, id).unwrap();
+    /// assert_eq!(board.archived_tasks().len(), 1);
+    ///
+    /// assert_eq!(board.purge_archived(), 1);
+    /// assert!(board.archived_tasks().is_empty());
+    /// ```
+    pub fn purge_archived(&mut self) -> usize {
+        let purged = self.archived.len();
+        self.archived.clear();
+        purged
+    }
+
+    /// Permanently removes tasks archived more than `days` days ago, according
+    /// to the current clock. Returns how many were removed.
+    ///
+    /// Tasks whose archive time can't be read are kept.
+    pub fn purge_archived_older_than(&mut self, days: i64) -> usize {
+        let now = clock::now();
+        let max_age = chrono::Duration::days(days);
+        let before = self.archived.len();
+        self.archived
+            .retain(|task| task.archived_age_at(now).is_none_or(|age| age <= max_age));
+        before - self.archived.len()
+    }
+
+    /// Reorders every column according to its sort mode
+    pub fn apply_sort_modes(&mut self) {
+        for column in &mut self.columns {
+            column.apply_sort();
+        }
+    }
+
+    /// Sets a column's sort mode and sorts it straight away
+    pub fn set_sort_mode(&mut self, column_index: usize, sort_mode: SortMode) -> Result<(), String> {
+        let column = self
+            .columns
+            .get_mut(column_index)
+            .ok_or("Column index out of bounds")?;
+        column.sort_mode = sort_mode;
+        column.apply_sort();
+        Ok(())
+    }
+
+    /// Renumbers every task's `order` to match its position in its column.
+    ///
+    /// Board methods keep the numbering up to date; call this after editing a
+    /// column's `tasks` directly (e.g. swapping or sorting them in bulk).
+    pub fn normalize_order(&mut self) {
+        for column in &mut self.columns {
+            column.normalize_order();
+        }
+    }
+
+    fn task_mut(&mut self, task_id: usize) -> Option<&mut Task> {
+        self.columns
+            .iter_mut()
+            .find_map(|column| column.task_by_id_mut(task_id))
+    }
+
+    /// Returns every task carrying exactly the given tag, with its column index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let id = board.add_task(1, "Fix crash").unwrap();
+    /// board.add_task_tag(1, id, "bug").unwrap();
+    ///
+    /// let tagged = board.tasks_with_tag("bug");
+    /// assert_eq!(tagged.len(), 1);
+    /// assert_eq!(tagged[0].1, 1);
+    /// assert!(board.tasks_with_tag("Bug").is_empty());
+    /// ```
+    pub fn tasks_with_tag(&self, tag: &str) -> Vec<(&Task, usize)> {
+        self.tasks_matching_tag(|t| t == tag)
+    }
+
+    /// Returns every task carrying the given tag, ignoring case, with its column index
+    pub fn tasks_with_tag_ci(&self, tag: &str) -> Vec<(&Task, usize)> {
+        let tag = tag.to_lowercase();
+        self.tasks_matching_tag(|t| t.to_lowercase() == tag)
+    }
+
+    /// Counts how many tasks carry each tag, most used first and ties by name.
+    ///
+    /// Tags are case-sensitive, as in [`Board::tasks_with_tag`]: `Bug` and
+    /// `bug` are counted separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let crash = board.add_task(0, "Fix crash").unwrap();
+    /// let docs = board.add_task(1, "Document crash").unwrap();
+    /// board.add_task_tag(0, crash, "bug").unwrap();
+    /// board.add_task_tag(0, crash, "urgent").unwrap();
+    /// board.add_task_tag(1, docs, "bug").unwrap();
+    ///
+    /// assert_eq!(
+    ///     board.tag_counts(),
+    ///     vec![("bug".to_string(), 2), ("urgent".to_string(), 1)]
+    /// );
+    /// ```
+    pub fn tag_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for tag in self.columns.iter().flat_map(|column| &column.tasks).flat_map(|task| &task.tags) {
+            *counts.entry(tag).or_default() += 1;
+        }
+
+        let mut counts: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect();
+        counts.sort_by(|(a_tag, a_count), (b_tag, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_tag.cmp(b_tag))
+        });
+        counts
+    }
+
+    fn tasks_matching_tag(&self, matches: impl Fn(&str) -> bool) -> Vec<(&Task, usize)> {
+        self.columns
+            .iter()
+            .enumerate()
+            .flat_map(|(col_idx, column)| column.tasks.iter().map(move |task| (task, col_idx)))
+            .filter(|(task, _)| task.tags.iter().any(|t| matches(t)))
+            .collect()
+    }
+
+    /// Moves every task carrying the given tag into the done column.
+    ///
+    /// Tags are matched case-sensitively, as in [`Board::tasks_with_tag`].
+    /// Tasks already in a done column stay where they are, so the returned
+    /// count only includes tasks that actually moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the board has no done column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// let login = board.add_task(0, "Login form").unwrap();
+    /// let logout = board.add_task(1, "Logout button").unwrap();
+    /// board.add_task_tag(0, login, "feature-x").unwrap();
+    /// board.add_task_tag(1, logout, "feature-x").unwrap();
+    ///
+    /// assert_eq!(board.move_tagged_to_done("feature-x").unwrap(), 2);
+    /// assert_eq!(board.columns[2].tasks.len(), 2);
+    /// assert_eq!(board.move_tagged_to_done("feature-x").unwrap(), 0);
+    /// ```
+    pub fn move_tagged_to_done(&mut self, tag: &str) -> Result<usize, String> {
+        let done = self.done_column_index().ok_or("Board has no done column")?;
+        let moving: Vec<(usize, usize)> = self
+            .tasks_with_tag(tag)
+            .into_iter()
+            .filter(|&(_, col_idx)| !self.is_done_column(col_idx))
+            .map(|(task, col_idx)| (col_idx, task.id))
+            .collect();
+
+        for &(col_idx, task_id) in &moving {
+            self.move_task(col_idx, done, task_id)?;
+        }
+        Ok(moving.len())
+    }
+
+    /// Sets which column counts as "done", or `None` to use the last column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column index is out of bounds.
+    pub fn set_done_column(&mut self, column_index: Option<usize>) -> Result<(), String> {
+        if let Some(idx) = column_index {
+            if idx >= self.columns.len() {
+                return Err("Column index out of bounds".to_string());
+            }
+        }
+
+        self.done_column = column_index;
+        Ok(())
+    }
+
+    /// Returns the index of the "done" column.
+    ///
+    /// Uses the explicitly configured column when it is in range, then the
+    /// first column named in [`Board::done_column_names`], and falls back to the
+    /// last column only if that list is empty. Returns `None` for a board
+    /// without columns or when no column has one of the listed names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project".to_string());
+    /// assert_eq!(board.done_column_index(), Some(2));
+    ///
+    /// board.set_done_column(Some(1)).unwrap();
+    /// assert!(board.is_done_column(1));
+    /// assert!(!board.is_done_column(2));
+    /// ```
+    pub fn done_column_index(&self) -> Option<usize> {
+        match self.done_column {
+            Some(idx) if idx < self.columns.len() => Some(idx),
+            _ if self.done_column_names.is_empty() => self.columns.len().checked_sub(1),
+            _ => (0..self.columns.len()).find(|&idx| self.is_named_done(idx)),
+        }
+    }
+
+    /// Checks whether the column at the given index is a "done" column.
+    ///
+    /// Without an explicit done column, every column named in
+    /// [`Board::done_column_names`] counts, so a board can have several.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.columns[2].name = "Shipped".to_string();
+    /// board.swap_columns(0, 2).unwrap();
+    /// board.done_column_names = vec!["shipped".to_string(), "Won't do".to_string()];
+    /// assert!(board.is_done_column(0));
+    /// assert!(!board.is_done_column(2));
+    /// ```
+    pub fn is_done_column(&self, column_index: usize) -> bool {
+        match self.done_column {
+            Some(idx) if idx < self.columns.len() => idx == column_index,
+            _ if self.done_column_names.is_empty() => {
+                self.columns.len().checked_sub(1) == Some(column_index)
+            }
+            _ => self.is_named_done(column_index),
+        }
+    }
+
+    /// Whether the column's name is in `done_column_names`, ignoring case
+    fn is_named_done(&self, column_index: usize) -> bool {
+        self.columns.get(column_index).is_some_and(|column| {
+            let name = column.name.to_lowercase();
+            self.done_column_names.iter().any(|done| done.to_lowercase() == name)
+        })
+    }
+
+    /// Returns the fraction of tasks in the "done" column, or 0.0 for a board without tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project".to_string());
+    /// assert_eq!(board.completion_ratio(), 0.0);
+    ///
+    /// board.add_task(0, "Open".to_string()).unwrap();
+    /// board.add_task(2, "Finished".to_string()).unwrap();
+    /// assert_eq!(board.completion_ratio(), 0.5);
+    /// ```
+    pub fn completion_ratio(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let total = self.total_tasks();
+
+        let done: usize = (0..self.columns.len())
+            .filter(|&idx| self.is_done_column(idx))
+            .map(|idx| self.columns[idx].tasks.len())
+            .sum();
+        done as f64 / total as f64
+    }
+
+    /// Sets or clears a column's work-in-progress limit
+    pub fn set_wip_limit(&mut self, column_index: usize, limit: Option<usize>) -> Result<(), String> {
+        let column = self
+            .columns
+            .get_mut(column_index)
+            .ok_or("Column index out of bounds")?;
+        column.wip_limit = limit;
+        Ok(())
+    }
+
+    /// Swaps two columns along with their tasks and settings.
+    ///
+    /// An explicitly chosen done column follows its column to the new position.
+    /// Swapping a column with itself does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.swap_columns(0, 1).unwrap();
+    /// assert_eq!(board.columns[0].name, "In Progress");
+    /// assert_eq!(board.columns[1].name, "To Do");
+    /// ```
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> Result<(), String> {
+        if a >= self.columns.len() || b >= self.columns.len() {
+            return Err("Column index out of bounds".to_string());
+        }
+        if a == b {
+            return Ok(());
+        }
+
+        self.columns.swap(a, b);
+        self.done_column = match self.done_column {
+            Some(idx) if idx == a => Some(b),
+            Some(idx) if idx == b => Some(a),
+            other => other,
+        };
+        Ok(())
+    }
+
+    /// Names of the board's columns, in order
+    pub fn column_names(&self) -> Vec<String> {
+        self.columns.iter().map(|column| column.name.clone()).collect()
+    }
+
+    /// Rebuilds the board's columns to match `names`, e.g. from another board.
+    ///
+    /// Columns whose name is kept (ignoring case, as with
+    /// [`Board::done_column_names`]) move to their new position along with
+    /// their tasks and settings, taking the new spelling; new names get empty
+    /// columns. Tasks in columns that
+    /// aren't kept are archived. Returns how many tasks were archived.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the board unchanged, if `names` is empty or
+    /// has duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(1, "Started").unwrap();
+    /// board.add_task(0, "Idea").unwrap();
+    ///
+    /// let names = vec!["Backlog".to_string(), "In Progress".to_string(), "Done".to_string()];
+    /// assert_eq!(board.set_columns_from(&names), Ok(1));
+    /// assert_eq!(board.column_names(), names);
+    /// assert_eq!(board.columns[1].tasks[0].title, "Started");
+    /// assert_eq!(board.archived_tasks()[0].title, "Idea");
+    /// ```
+    pub fn set_columns_from(&mut self, names: &[String]) -> Result<usize, String> {
+        if names.is_empty() {
+            return Err("A board needs at least one column".to_string());
+        }
+        let keys: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+        for (idx, key) in keys.iter().enumerate() {
+            if keys[..idx].contains(key) {
+                return Err(format!("Duplicate column name '{}'", names[idx]));
+            }
+        }
+
+        let done_key = self
+            .done_column
+            .and_then(|idx| self.columns.get(idx))
+            .map(|c| c.name.to_lowercase());
+        let mut old_columns = std::mem::take(&mut self.columns);
+        self.columns = names
+            .iter()
+            .zip(&keys)
+            .map(|(name, key)| match old_columns.iter().position(|c| c.name.to_lowercase() == *key) {
+                Some(idx) => {
+                    let mut column = old_columns.remove(idx);
+                    column.name = name.clone();
+                    column
+                }
+                None => Column::new(name.clone()),
+            })
+            .collect();
+        self.done_column = done_key.and_then(|done| keys.iter().position(|key| *key == done));
+
+        let mut archived = 0;
+        for mut task in old_columns.into_iter().flat_map(|column| column.tasks) {
+            task.mark_archived();
+            self.archived.push(task);
+            archived += 1;
+        }
+        Ok(archived)
+    }
+
+    /// Sorts a column's tasks oldest first (see [`Column::sort_by_age`])
+    pub fn sort_column_by_age(&mut self, column_index: usize) -> Result<(), String> {
+        let column = self
+            .columns
+            .get_mut(column_index)
+            .ok_or("Column index out of bounds")?;
+        column.sort_by_age();
+        Ok(())
+    }
+
+    /// Returns a column's current task count and its WIP limit, if any.
+    ///
+    /// Out-of-range columns report `(0, None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.set_wip_limit(1, Some(5)).unwrap();
+    /// board.add_task(1, "Doing").unwrap();
+    /// assert_eq!(board.column_load(1), (1, Some(5)));
+    /// ```
+    pub fn column_load(&self, column_index: usize) -> (usize, Option<usize>) {
+        self.columns
+            .get(column_index)
+            .map_or((0, None), |column| (column.tasks.len(), column.wip_limit))
+    }
+
+    /// Returns the indices of columns holding more tasks than their WIP limit
+    pub fn over_wip_columns(&self) -> Vec<usize> {
+        (0..self.columns.len())
+            .filter(|&idx| matches!(self.column_load(idx), (count, Some(limit)) if count > limit))
+            .collect()
+    }
+
+    /// Returns the number of tasks across all columns
+    pub fn total_tasks(&self) -> usize {
+        self.columns.iter().map(|c| c.tasks.len()).sum()
+    }
+
+    /// Returns true when no column holds any task
+    pub fn is_empty(&self) -> bool {
+        self.columns.iter().all(Column::is_empty)
+    }
+
+    /// Checks the board's invariants, e.g. before saving an imported or
+    /// hand-edited board.
+    ///
+    /// Returns every problem found rather than stopping at the first: boards
+    /// need at least one column, non-empty column names, unique task ids that
+    /// are all below the next id to be handed out, and due dates in one of the
+    /// accepted formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(0, "Ship it").unwrap();
+    /// assert!(board.validate().is_ok());
+    ///
+    /// board.columns[0].tasks[0].due_date = Some("someday".to_string());
+    /// board.columns[1].name.clear();
+    /// assert_eq!(board.validate().unwrap_err().len(), 2);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if self.columns.is_empty() {
+            problems.push("Board has no columns".to_string());
+        }
+        for (idx, column) in self.columns.iter().enumerate() {
+            if column.name.trim().is_empty() {
+                problems.push(format!("Column {} has an empty name", idx + 1));
+            }
+        }
+
+        let mut seen = Vec::new();
+        for (column, task) in self
+            .columns
+            .iter()
+            .flat_map(|column| column.tasks.iter().map(move |task| (column, task)))
+        {
+            if seen.contains(&task.id) {
+                problems.push(format!("Task id {} is used more than once", task.id));
+            } else {
+                seen.push(task.id);
+            }
+            if task.id >= self.next_task_id {
+                problems.push(format!(
+                    "Task id {} is not below the next task id ({})",
+                    task.id, self.next_task_id
+                ));
+            }
+            if let Some(due_date) = &task.due_date {
+                if !Task::is_valid_due_date(due_date) {
+                    problems.push(format!(
+                        "Task {} in '{}' has an invalid due date '{}'",
+                        task.id, column.name, due_date
+                    ));
+                }
+            }
+            if let Some(start_date) = &task.start_date {
+                if !Task::is_valid_due_date(start_date) {
+                    problems.push(format!(
+                        "Task {} in '{}' has an invalid start date '{}'",
+                        task.id, column.name, start_date
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Sum of the estimates of every task on the board; unestimated tasks count as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Sprint");
+    /// board.add_task(0, "Login").unwrap();
+    /// board.add_task(2, "Signup").unwrap();
+    /// board.add_task(1, "Unestimated").unwrap();
+    /// board.columns[0].tasks[0].estimate = Some(5);
+    /// board.columns[2].tasks[0].estimate = Some(3);
+    ///
+    /// assert_eq!(board.total_estimate(), 8);
+    /// assert_eq!(board.remaining_estimate(), 5);
+    /// ```
+    pub fn total_estimate(&self) -> u32 {
+        self.column_estimate_breakdown().iter().map(|(_, points)| points).sum()
+    }
+
+    /// Sum of the estimates of every task not in the done column
+    pub fn remaining_estimate(&self) -> u32 {
+        self.column_estimate_breakdown()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !self.is_done_column(*idx))
+            .map(|(_, (_, points))| points)
+            .sum()
+    }
+
+    /// Each column's name with the sum of its tasks' estimates, in board order
+    pub fn column_estimate_breakdown(&self) -> Vec<(String, u32)> {
+        self.columns
+            .iter()
+            .map(|column| {
+                let points = column.tasks.iter().filter_map(|task| task.estimate).sum();
+                (column.name.clone(), points)
+            })
+            .collect()
+    }
+
+    /// Builds a serializable snapshot of the board for frontends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.add_task(1, "Doing").unwrap();
+    ///
+    /// let view = board.to_view();
+    /// assert_eq!(view.tasks[0].column, "In Progress");
+    /// assert!(serde_json::to_value(&view).unwrap().get("next_task_id").is_none());
+    /// ```
+    pub fn to_view(&self) -> BoardView {
+        let columns = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| ColumnView {
+                name: column.name.clone(),
+                task_count: column.tasks.len(),
+                wip_limit: column.wip_limit,
+                is_done: self.is_done_column(idx),
+            })
+            .collect();
+
+        let tasks = self
+            .columns
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, column)| column.tasks.iter().map(move |task| (idx, column, task)))
+            .map(|(idx, column, task)| TaskView {
+                id: task.id,
+                title: task.title.clone(),
+                description: task.description.clone(),
+                priority: self.priority_label(task),
+                tags: task.tags.clone(),
+                watchers: task.watchers.clone(),
+                due_date: task.due_date.clone(),
+                created_at: task.created_at.clone(),
+                updated_at: task.updated_at.clone(),
+                column: column.name.clone(),
+                column_index: idx,
+                overdue: !self.is_done_column(idx) && task.is_overdue(),
+                age_seconds: task.age().map(|age| age.num_seconds()),
+            })
+            .collect();
+
+        BoardView {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            columns,
+            tasks,
+            completion_ratio: self.completion_ratio(),
+        }
+    }
+
+    /// Returns the number of overdue tasks outside the done column
+    pub fn overdue_count(&self) -> usize {
+        self.columns
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !self.is_done_column(*idx))
+            .flat_map(|(_, column)| &column.tasks)
+            .filter(|task| task.is_overdue())
+            .count()
+    }
+
+    /// Finds the index of the column with exactly the given name (case-sensitive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Board;
+    ///
+    /// let board = Board::new("Project".to_string());
+    /// assert_eq!(board.column_index_by_name("Done"), Some(2));
+    /// assert_eq!(board.column_index_by_name("done"), None);
+    /// ```
+    pub fn column_index_by_name(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c.name == name)
+    }
+
+    /// Finds the index of the column with the given name, ignoring case
+    pub fn column_index_by_name_ci(&self, name: &str) -> Option<usize> {
+        let name = name.to_lowercase();
+        self.columns
+            .iter()
+            .position(|c| c.name.to_lowercase() == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_creation() {
+        let board = Board::new("My Board");
+        assert_eq!(board.name, "My Board");
+        assert_eq!(board.columns.len(), 3);
+        assert_eq!(board.columns[0].name, "To Do");
+        assert_eq!(board.columns[1].name, "In Progress");
+        assert_eq!(board.columns[2].name, "Done");
+    }
+
+    #[test]
+    fn test_board_add_task() {
+        let mut board = Board::new("Test");
+        let result = board.add_task(0, "New task");
+
+        assert!(result.is_ok());
+        assert_eq!(board.columns[0].tasks.len(), 1);
+        assert_eq!(board.columns[0].tasks[0].title, "New task");
+    }
+
+    #[test]
+    fn test_board_move_task() {
+        let mut board = Board::new("Test");
+        let task_id = board.add_task(0, "Task to move").unwrap();
+
+        let result = board.move_task(0, 1, task_id);
+        assert!(result.is_ok());
+        assert_eq!(board.columns[0].tasks.len(), 0);
+        assert_eq!(board.columns[1].tasks.len(), 1);
+        assert_eq!(board.columns[1].tasks[0].title, "Task to move");
+    }
+
+    #[test]
+    fn test_move_task_to_inserts_into_middle() {
+        let mut board = Board::new("Test");
+        let first = board.add_task(1, "First").unwrap();
+        let second = board.add_task(1, "Second").unwrap();
+        let moved = board.add_task(0, "Moved").unwrap();
+
+        board.move_task_to(0, 1, moved, 1).unwrap();
+        let ids: Vec<usize> = board.columns[1].tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![first, moved, second]);
+        assert_eq!(board.columns[1].tasks[1].order, 1);
+        assert!(board.columns[0].tasks.is_empty());
+    }
+
+    #[test]
+    fn test_move_task_to_clamps_index_and_checks_bounds() {
+        let mut board = Board::new("Test");
+        let first = board.add_task(1, "First").unwrap();
+        let moved = board.add_task(0, "Moved").unwrap();
+
+        board.move_task_to(0, 1, moved, 42).unwrap();
+        let ids: Vec<usize> = board.columns[1].tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![first, moved]);
+
+        // Reordering within a column works too, without adding to its history
+        let history = board.columns[1].tasks[1].column_history.len();
+        board.move_task_to(1, 1, moved, 0).unwrap();
+        assert_eq!(board.columns[1].tasks[0].id, moved);
+        assert_eq!(board.columns[1].tasks[0].column_history.len(), history);
+
+        assert!(board.move_task_to(1, 5, moved, 0).is_err());
+        assert!(board.move_task_to(0, 1, moved, 0).is_err());
+    }
+
+    #[test]
+    fn test_board_move_task_invalid_column() {
+        let mut board = Board::new("Test");
+        let task_id = board.add_task(0, "Task").unwrap();
+
+        let result = board.move_task(0, 10, task_id);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_board_update_task_title() {
+        let mut board = Board::new("Test");
+        let task_id = board.add_task(0, "Original Title").unwrap();
+
+        // Update the task title
+        let result = board.update_task_title(0, task_id, "Updated Title");
+        assert_eq!(result, Ok("Original Title".to_string()));
+
+        // Verify the title was updated
+        assert_eq!(board.columns[0].tasks[0].title, "Updated Title");
+    }
+
+    #[test]
+    fn test_board_update_task_title_invalid_column() {
+        let mut board = Board::new("Test");
+        let task_id = board.add_task(0, "Task").unwrap();
+
+        // Try to update task in non-existent column
+        let result = board.update_task_title(10, task_id, "New Title");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_board_update_task_title_invalid_task() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Task").unwrap();
+
+        // Try to update non-existent task
+        let result = board.update_task_title(0, 9999, "New Title");
         assert!(result.is_err());
     }
 
     #[test]
-    fn test_board_update_task_title() {
+    fn test_column_index_by_name() {
+        let board = Board::new("Test");
+        assert_eq!(board.column_index_by_name("To Do"), Some(0));
+        assert_eq!(board.column_index_by_name("In Progress"), Some(1));
+        assert_eq!(board.column_index_by_name("Backlog"), None);
+        assert_eq!(board.column_index_by_name("in progress"), None);
+    }
+
+    #[test]
+    fn test_column_index_by_name_ci() {
+        let board = Board::new("Test");
+        assert_eq!(board.column_index_by_name_ci("in progress"), Some(1));
+        assert_eq!(board.column_index_by_name_ci("DONE"), Some(2));
+        assert_eq!(board.column_index_by_name_ci("Backlog"), None);
+    }
+
+    #[test]
+    fn test_done_column_defaults_to_last() {
+        let board = Board::new("Test");
+        assert_eq!(board.done_column_index(), Some(2));
+        assert!(board.is_done_column(2));
+        assert!(!board.is_done_column(0));
+    }
+
+    #[test]
+    fn test_done_column_explicit_index() {
+        let mut board = Board::new("Test");
+        board.set_done_column(Some(1)).unwrap();
+        assert_eq!(board.done_column_index(), Some(1));
+        assert!(board.is_done_column(1));
+        assert!(!board.is_done_column(2));
+
+        board.set_done_column(None).unwrap();
+        assert_eq!(board.done_column_index(), Some(2));
+    }
+
+    #[test]
+    fn test_done_column_rejects_out_of_range() {
+        let mut board = Board::new("Test");
+        assert!(board.set_done_column(Some(5)).is_err());
+        assert_eq!(board.done_column_index(), Some(2));
+    }
+
+    #[test]
+    fn test_done_column_ignores_out_of_range_stored_index() {
+        let mut board = Board::new("Test");
+        board.set_done_column(Some(1)).unwrap();
+
+        let mut json: serde_json::Value = serde_json::to_value(&board).unwrap();
+        json["done_column"] = serde_json::json!(7);
+        let loaded: Board = serde_json::from_value(json).unwrap();
+
+        assert_eq!(loaded.done_column_index(), Some(2));
+    }
+
+    #[test]
+    fn test_done_column_missing_from_old_boards() {
+        let json = r#"{"name":"Old","columns":[{"name":"A","tasks":[]},{"name":"B","tasks":[]}],"next_task_id":1}"#;
+        let board: Board = serde_json::from_str(json).unwrap();
+        assert_eq!(board.done_column_index(), Some(1));
+    }
+
+    #[test]
+    fn test_done_column_by_name_survives_reordering() {
+        let mut board = Board::new("Test");
+        board.swap_columns(1, 2).unwrap();
+        assert_eq!(board.columns[1].name, "Done");
+        assert_eq!(board.done_column_index(), Some(1));
+        assert!(board.is_done_column(1));
+        assert!(!board.is_done_column(2));
+    }
+
+    #[test]
+    fn test_done_column_by_name_ignores_case() {
+        let mut board = Board::with_columns(
+            "Test",
+            vec!["Backlog".to_string(), "SHIPPED".to_string(), "Later".to_string()],
+        )
+        .unwrap();
+        // Boards built from column names fall back to the last column
+        assert_eq!(board.done_column_index(), Some(2));
+
+        board.done_column_names = vec!["shipped".to_string()];
+        assert_eq!(board.done_column_index(), Some(1));
+        assert!(!board.is_done_column(2));
+    }
+
+    #[test]
+    fn test_done_column_renamed_away() {
+        let mut board = Board::new("Test");
+        board.columns[2].name = "Finished".to_string();
+        assert_eq!(board.done_column_index(), None);
+        assert!(!board.is_done_column(2));
+
+        board.done_column_names.clear();
+        assert_eq!(board.done_column_index(), Some(2));
+    }
+
+    #[test]
+    fn test_several_done_columns_by_name() {
+        let mut board = Board::with_columns(
+            "Test",
+            vec!["Open".to_string(), "Done".to_string(), "Won't do".to_string()],
+        )
+        .unwrap();
+        board.done_column_names = vec!["done".to_string(), "won't do".to_string()];
+        board.add_task(0, "Open").unwrap();
+        board.add_task(1, "Finished").unwrap();
+        board.add_task(2, "Dropped").unwrap();
+        board.add_task(2, "Also dropped").unwrap();
+
+        assert!(board.is_done_column(1));
+        assert!(board.is_done_column(2));
+        assert_eq!(board.done_column_index(), Some(1));
+        assert_eq!(board.completion_ratio(), 0.75);
+    }
+
+    #[test]
+    fn test_explicit_done_column_overrides_names() {
+        let mut board = Board::new("Test");
+        board.set_done_column(Some(0)).unwrap();
+        assert!(board.is_done_column(0));
+        assert!(!board.is_done_column(2));
+    }
+
+    #[test]
+    fn test_completion_ratio_empty_board() {
+        let board = Board::new("Test");
+        assert_eq!(board.completion_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_completion_ratio_all_done() {
+        let mut board = Board::new("Test");
+        board.add_task(2, "Done 1").unwrap();
+        board.add_task(2, "Done 2").unwrap();
+        assert_eq!(board.completion_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_completion_ratio_half_done() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Todo").unwrap();
+        board.add_task(1, "Doing").unwrap();
+        board.add_task(2, "Done 1").unwrap();
+        board.add_task(2, "Done 2").unwrap();
+        assert_eq!(board.completion_ratio(), 0.5);
+    }
+
+    #[test]
+    fn test_completion_ratio_uses_done_column() {
+        let mut board = Board::new("Test");
+        board.add_task(1, "Middle").unwrap();
+        board.add_task(2, "Last").unwrap();
+        board.set_done_column(Some(1)).unwrap();
+        assert_eq!(board.completion_ratio(), 0.5);
+    }
+
+    fn p_scheme() -> Vec<String> {
+        ["P0", "P1", "P2", "P3", "P4"].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn test_default_priority_scheme() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Task").unwrap();
+        assert_eq!(board.priority_scheme(), vec!["High", "Medium", "Low", "None"]);
+
+        board.cycle_task_priority(0, id).unwrap();
+        let task = board.get_task(id).unwrap().0;
+        assert_eq!(board.priority_label(task), "Low");
+        assert_eq!(task.priority_level, None);
+    }
+
+    #[test]
+    fn test_priority_change_reorders_priority_sorted_column() {
+        let mut board = Board::new("Test");
+        board.set_sort_mode(0, SortMode::Priority).unwrap();
+        let routine = board.add_task(0, "Routine").unwrap();
+        let urgent = board.add_task(0, "Urgent").unwrap();
+
+        board.cycle_task_priority(0, urgent).unwrap();
+        assert_eq!(board.columns[0].tasks[0].id, urgent);
+
+        board.set_task_priority(0, routine, Priority::High).unwrap();
+        assert_eq!(board.columns[0].tasks[0].id, routine);
+        assert_eq!(board.columns[0].tasks[0].order, 0);
+    }
+
+    #[test]
+    fn test_priority_change_keeps_manual_order() {
+        let mut board = Board::new("Test");
+        let routine = board.add_task(0, "Routine").unwrap();
+        let urgent = board.add_task(0, "Urgent").unwrap();
+
+        board.cycle_task_priority(0, urgent).unwrap();
+        board.set_task_priority(0, urgent, Priority::High).unwrap();
+        let ids: Vec<usize> = board.columns[0].tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![routine, urgent]);
+        assert!(board.set_task_priority(0, 99, Priority::Low).is_err());
+        assert!(board.set_task_priority(9, urgent, Priority::Low).is_err());
+    }
+
+    #[test]
+    fn test_set_priority_for_skips_unknown_ids() {
+        let mut board = Board::new("Test");
+        let first = board.add_task(0, "First").unwrap();
+        let second = board.add_task(2, "Second").unwrap();
+        let untouched = board.add_task(0, "Untouched").unwrap();
+
+        assert_eq!(board.set_priority_for(&[first, 404, second], Priority::High), 2);
+        assert_eq!(board.get_task(first).unwrap().0.priority, Priority::High);
+        assert_eq!(board.get_task(second).unwrap().0.priority, Priority::High);
+        assert_eq!(board.get_task(untouched).unwrap().0.priority, Priority::None);
+
+        assert_eq!(board.set_priority_for(&[404], Priority::Low), 0);
+        assert_eq!(board.set_priority_for(&[], Priority::Low), 0);
+    }
+
+    #[test]
+    fn test_set_priority_for_resorts_priority_columns() {
+        let mut board = Board::new("Test");
+        board.set_sort_mode(0, SortMode::Priority).unwrap();
+        board.add_task(0, "Routine").unwrap();
+        let urgent = board.add_task(0, "Urgent").unwrap();
+
+        board.set_priority_for(&[urgent], Priority::High);
+        assert_eq!(board.columns[0].tasks[0].id, urgent);
+    }
+
+    #[test]
+    fn test_cycle_custom_priority_scheme() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Task").unwrap();
+        board.set_priority_scheme(p_scheme());
+
+        // New tasks start at the lowest level and step up, wrapping around
+        let label = |board: &Board| board.priority_label(board.get_task(id).unwrap().0);
+        assert_eq!(label(&board), "P4");
+        let expected = ["P3", "P2", "P1", "P0", "P4"];
+        for name in expected {
+            board.cycle_task_priority(0, id).unwrap();
+            assert_eq!(label(&board), name);
+        }
+
+        // The built-in priority follows for colors and symbols
+        board.cycle_task_priority(0, id).unwrap();
+        board.cycle_task_priority(0, id).unwrap();
+        assert_eq!(label(&board), "P2");
+        assert_eq!(board.get_task(id).unwrap().0.priority, Priority::Medium);
+    }
+
+    #[test]
+    fn test_migrate_old_board_to_custom_scheme() {
+        let json = r#"{
+            "name": "Old",
+            "columns": [{"name": "To Do", "tasks": [
+                {"id": 1, "title": "Urgent", "description": null, "priority": "High"},
+                {"id": 2, "title": "Later", "description": null, "priority": "Low"},
+                {"id": 3, "title": "Someday", "description": null}
+            ]}],
+            "next_task_id": 4
+        }"#;
+        let mut board: Board = serde_json::from_str(json).unwrap();
+        assert_eq!(board.priority_label(&board.columns[0].tasks[0]), "High");
+
+        board.set_priority_scheme(p_scheme());
+        let labels: Vec<String> = board.columns[0]
+            .tasks
+            .iter()
+            .map(|t| board.priority_label(t))
+            .collect();
+        assert_eq!(labels, vec!["P0", "P3", "P4"]);
+
+        // The scheme and levels survive a round trip
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.priority_scheme(), p_scheme());
+        assert_eq!(loaded.columns[0].tasks[1].priority_level, Some(3));
+    }
+
+    #[test]
+    fn test_shrinking_scheme_clamps_levels() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Task").unwrap();
+        board.set_priority_scheme(p_scheme());
+        board.columns[0].tasks[0].priority_level = Some(4);
+
+        board.set_priority_scheme(vec!["Now".to_string(), "Later".to_string()]);
+        assert_eq!(board.priority_label(&board.columns[0].tasks[0]), "Later");
+
+        // Restoring the built-in levels keeps the closest built-in priority
+        board.set_priority_scheme(Vec::new());
+        assert_eq!(board.columns[0].tasks[0].priority_level, None);
+        assert_eq!(board.priority_label(&board.columns[0].tasks[0]), "Low");
+    }
+
+    #[test]
+    fn test_tag_all_in_column() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "One").unwrap();
+        board.add_task(0, "Two").unwrap();
+        board.add_task(1, "Elsewhere").unwrap();
+        board.add_task_tag(0, 1, "release").unwrap();
+
+        assert_eq!(board.tag_all_in_column(0, "release").unwrap(), 1);
+        assert!(board.columns[0].tasks.iter().all(|t| t.tags == vec!["release"]));
+        assert!(board.columns[1].tasks[0].tags.is_empty());
+
+        // Idempotent when re-run
+        assert_eq!(board.tag_all_in_column(0, "release").unwrap(), 0);
+        assert_eq!(board.tag_all_in_column(0, "").unwrap(), 0);
+        assert!(board.tag_all_in_column(9, "release").is_err());
+    }
+
+    #[test]
+    fn test_untag_all_in_column() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "One").unwrap();
+        board.add_task(0, "Two").unwrap();
+        board.add_task_tag(0, 1, "stale").unwrap();
+
+        assert_eq!(board.untag_all_in_column(0, "stale").unwrap(), 1);
+        assert_eq!(board.untag_all_in_column(0, "stale").unwrap(), 0);
+        assert!(board.untag_all_in_column(9, "stale").is_err());
+    }
+
+    #[test]
+    fn test_record_title_dedupes_and_caps() {
+        let mut board = Board::new("Test");
+        board.record_title("Fix bug");
+        board.record_title("Fix bug");
+        board.record_title("  ");
+        board.record_title("Write docs");
+        board.record_title("Fix bug");
+        assert_eq!(board.title_history(), ["Fix bug", "Write docs", "Fix bug"]);
+
+        for i in 0..Board::TITLE_HISTORY_LIMIT {
+            board.record_title(&format!("Task {}", i));
+        }
+        assert_eq!(board.title_history().len(), Board::TITLE_HISTORY_LIMIT);
+        assert_eq!(board.title_history()[0], "Task 0");
+    }
+
+    #[test]
+    fn test_description_round_trips_through_json() {
+        let mut board = Board::new("Marketing");
+        board.set_description("Q3 marketing tasks");
+
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.description, "Q3 marketing tasks");
+
+        // Boards saved before descriptions existed load with an empty one
+        let old = json.replace(r#""description":"Q3 marketing tasks","#, "");
+        assert!(!old.contains("description"));
+        let loaded: Board = serde_json::from_str(&old).unwrap();
+        assert_eq!(loaded.description, "");
+    }
+
+    #[test]
+    fn test_title_history_round_trips_through_json() {
+        let mut board = Board::new("Test");
+        board.record_title("Remembered");
+        let json = serde_json::to_string(&board).unwrap();
+        let loaded: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.title_history(), ["Remembered"]);
+    }
+
+    #[test]
+    fn test_task_watchers() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Task").unwrap();
+
+        board.add_task_watcher(0, id, "alice").unwrap();
+        board.add_task_watcher(0, id, "alice").unwrap();
+        assert_eq!(board.columns[0].tasks[0].watchers, vec!["alice"]);
+
+        board.remove_task_watcher(0, id, "alice").unwrap();
+        assert!(board.columns[0].tasks[0].watchers.is_empty());
+
+        assert!(board.add_task_watcher(1, id, "bob").is_err());
+        assert!(board.add_task_watcher(9, id, "bob").is_err());
+    }
+
+    #[test]
+    fn test_move_task_to_column_by_name() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Task").unwrap();
+
+        board.move_task_to_column_by_name(id, "In Progress").unwrap();
+        assert!(board.columns[0].tasks.is_empty());
+        assert_eq!(board.get_task(id).unwrap().1, 1);
+
+        // Moving into the column it's already in keeps it there
+        board.move_task_to_column_by_name(id, "In Progress").unwrap();
+        assert_eq!(board.columns[1].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_move_task_to_column_by_name_errors() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Task").unwrap();
+
+        let err = board.move_task_to_column_by_name(id, "Backlog").unwrap_err();
+        assert!(err.contains("Backlog"));
+        assert!(board.move_task_to_column_by_name(id, "done").is_err());
+        assert!(board.move_task_to_column_by_name(999, "Done").is_err());
+        assert_eq!(board.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_moves_record_column_history() {
         let mut board = Board::new("Test");
-        let task_id = board.add_task(0, "Original Title").unwrap();
+        let id = board.add_task(0, "Task").unwrap();
+        board.move_task(0, 1, id).unwrap();
+        board.move_task(1, 2, id).unwrap();
 
-        // Update the task title
-        let result = board.update_task_title(0, task_id, "Updated Title");
-        assert!(result.is_ok());
+        let (task, _) = board.get_task(id).unwrap();
+        let columns: Vec<&str> = task.column_history.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(columns, ["To Do", "In Progress", "Done"]);
+        assert!(task.time_in_current_column().is_some());
+    }
 
-        // Verify the title was updated
-        assert_eq!(board.columns[0].tasks[0].title, "Updated Title");
+    #[test]
+    fn test_insert_task_copy_assigns_fresh_id() {
+        let mut source = Board::new("Source");
+        let id = source.add_task(0, "Copied").unwrap();
+        source.add_task_tag(0, id, "bug").unwrap();
+        let (task, _) = source.get_task(id).unwrap();
+
+        let mut board = Board::new("Target");
+        board.add_task(0, "Existing").unwrap();
+        let new_id = board.insert_task_copy(1, task).unwrap();
+
+        assert_ne!(new_id, 1);
+        let (copy, column) = board.get_task(new_id).unwrap();
+        assert_eq!(column, 1);
+        assert_eq!(copy.title, "Copied");
+        assert_eq!(copy.tags, vec!["bug"]);
+        assert_eq!(copy.column_history.len(), 1);
+        assert!(board.insert_task_copy(9, task).is_err());
     }
 
     #[test]
-    fn test_board_update_task_title_invalid_column() {
+    fn test_total_and_overdue_counts() {
+        let mut board = Board::new("Test");
+        assert_eq!(board.total_tasks(), 0);
+
+        let late = board.add_task(0, "Late").unwrap();
+        board.add_task(0, "On time").unwrap();
+        let finished = board.add_task(2, "Finished late").unwrap();
+        board.set_task_due_date(0, late, Some("2000-01-01".to_string())).unwrap();
+        board.set_task_due_date(2, finished, Some("2000-01-01".to_string())).unwrap();
+
+        assert_eq!(board.total_tasks(), 3);
+        // Overdue tasks in the done column don't count
+        assert_eq!(board.overdue_count(), 1);
+    }
+
+    #[test]
+    fn test_sort_column_by_age() {
+        let mut board = Board::new("Test");
+        let newer = board.add_task(0, "Newer").unwrap();
+        let older = board.add_task(0, "Older").unwrap();
+        board.columns[0].tasks[0].created_at = "2024-02-01 10:00:00".to_string();
+        board.columns[0].tasks[1].created_at = "2024-01-01 10:00:00".to_string();
+
+        board.sort_column_by_age(0).unwrap();
+        let ids: Vec<usize> = board.columns[0].tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![older, newer]);
+        assert!(board.sort_column_by_age(9).is_err());
+    }
+
+    #[test]
+    fn test_swap_columns() {
         let mut board = Board::new("Test");
         let task_id = board.add_task(0, "Task").unwrap();
+        board.set_done_column(Some(1)).unwrap();
 
-        // Try to update task in non-existent column
-        let result = board.update_task_title(10, task_id, "New Title");
-        assert!(result.is_err());
+        board.swap_columns(0, 1).unwrap();
+        assert_eq!(board.columns[0].name, "In Progress");
+        assert_eq!(board.columns[1].name, "To Do");
+        assert_eq!(board.columns[1].tasks[0].id, task_id);
+        assert_eq!(board.done_column_index(), Some(0));
     }
 
     #[test]
-    fn test_board_update_task_title_invalid_task() {
+    fn test_swap_columns_out_of_bounds() {
+        let mut board = Board::new("Test");
+        assert!(board.swap_columns(0, 3).is_err());
+        assert!(board.swap_columns(3, 0).is_err());
+        assert!(board.swap_columns(3, 3).is_err());
+        assert_eq!(board.columns[0].name, "To Do");
+    }
+
+    #[test]
+    fn test_swap_column_with_itself_is_noop() {
+        let mut board = Board::new("Test");
+        board.add_task(1, "Task").unwrap();
+        board.swap_columns(1, 1).unwrap();
+        assert_eq!(board.columns[1].name, "In Progress");
+        assert_eq!(board.columns[1].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_to_view_flattens_tasks_with_derived_fields() {
+        let now = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let _clock = crate::clock::set_thread_clock(crate::clock::FixedClock(now));
+
+        let mut board = Board::new("Test");
+        let late = board.add_task(0, "Late").unwrap();
+        let done = board.add_task(2, "Done late").unwrap();
+        board.set_task_due_date(0, late, Some("2024-03-01".to_string())).unwrap();
+        board.set_task_due_date(2, done, Some("2024-03-01".to_string())).unwrap();
+        board.cycle_task_priority(0, late).unwrap();
+        board.columns[0].tasks[0].created_at = "2024-03-14 12:00:00".to_string();
+
+        let view = board.to_view();
+        assert_eq!(view.name, "Test");
+        assert_eq!(view.columns.len(), 3);
+        assert_eq!(view.columns[0].task_count, 1);
+        assert!(view.columns[2].is_done);
+        assert_eq!(view.completion_ratio, 0.5);
+
+        assert_eq!(view.tasks.len(), 2);
+        let late_view = &view.tasks[0];
+        assert_eq!(late_view.column, "To Do");
+        assert_eq!(late_view.priority, "Low");
+        assert!(late_view.overdue);
+        assert_eq!(late_view.age_seconds, Some(24 * 60 * 60));
+
+        // Overdue tasks in the done column aren't flagged
+        assert_eq!(view.tasks[1].column_index, 2);
+        assert!(!view.tasks[1].overdue);
+
+        let json = serde_json::to_value(&view).unwrap();
+        assert!(json.get("next_task_id").is_none());
+        assert_eq!(json["tasks"][0]["overdue"], true);
+    }
+
+    #[test]
+    fn test_find_duplicates_groups_by_normalized_title() {
+        let mut board = Board::new("Test");
+        let a1 = board.add_task(0, "Deploy").unwrap();
+        let b1 = board.add_task(0, "Review").unwrap();
+        board.add_task(1, "Unique").unwrap();
+        let a2 = board.add_task(1, "  deploy ").unwrap();
+        let b2 = board.add_task(2, "REVIEW").unwrap();
+        let a3 = board.add_task(2, "Deploy").unwrap();
+
+        assert_eq!(board.find_duplicates(), vec![vec![a1, a2, a3], vec![b1, b2]]);
+        assert!(Board::new("Empty").find_duplicates().is_empty());
+    }
+
+    #[test]
+    fn test_dedupe_keeps_first_and_merges_tags() {
+        let mut board = Board::new("Test");
+        let first = board.add_task(0, "Deploy").unwrap();
+        let second = board.add_task(1, "deploy").unwrap();
+        let third = board.add_task(2, "Deploy").unwrap();
+        board.add_task(2, "Other").unwrap();
+        board.add_task_tag(0, first, "ops").unwrap();
+        board.add_task_tag(1, second, "release").unwrap();
+        board.add_task_tag(2, third, "ops").unwrap();
+
+        assert_eq!(board.dedupe(), 2);
+        assert_eq!(board.total_tasks(), 2);
+        let (kept, column) = board.get_task(first).unwrap();
+        assert_eq!(column, 0);
+        assert_eq!(kept.tags, vec!["ops", "release"]);
+        assert!(board.get_task(second).is_none());
+        assert!(board.get_task(third).is_none());
+
+        assert_eq!(board.dedupe(), 0);
+    }
+
+    #[test]
+    fn test_stable_ids_opt_in() {
+        let mut board = Board::new("Test");
+        let old = board.add_task(0, "Old").unwrap();
+        assert_eq!(board.assign_missing_uuids(), 0);
+        assert!(board.get_task(old).unwrap().0.uuid.is_none());
+        let json = serde_json::to_value(&board).unwrap();
+        assert!(json.get("stable_ids").is_none());
+
+        board.enable_stable_ids();
+        assert!(board.has_stable_ids());
+        assert!(board.get_task(old).unwrap().0.uuid.is_some());
+        let new = board.add_task(1, "New").unwrap();
+        let uuid = board.get_task(new).unwrap().0.uuid.clone().unwrap();
+        assert_eq!(board.find_by_uuid(&uuid).unwrap().1, 1);
+        assert!(board.find_by_uuid("missing").is_none());
+
+        let restored: Board = serde_json::from_str(&serde_json::to_string(&board).unwrap()).unwrap();
+        assert!(restored.has_stable_ids());
+        assert_eq!(restored.get_task(new).unwrap().0.uuid, Some(uuid));
+    }
+
+    #[test]
+    fn test_insert_task_copy_gets_fresh_uuid() {
+        let mut board = Board::new("Test");
+        board.enable_stable_ids();
+        let original = board.add_task(0, "Original").unwrap();
+        let task = board.get_task(original).unwrap().0.clone();
+
+        let copy = board.insert_task_copy(1, &task).unwrap();
+        let copy_uuid = board.get_task(copy).unwrap().0.uuid.clone();
+        assert!(copy_uuid.is_some());
+        assert_ne!(copy_uuid, task.uuid);
+    }
+
+    #[test]
+    fn test_merge_produces_no_uuid_collisions() {
+        let mut ours = Board::new("Ours");
+        ours.enable_stable_ids();
+        let mut theirs = Board::new("Theirs");
+        theirs.enable_stable_ids();
+        for i in 0..20 {
+            ours.add_task(i % 3, format!("Ours {}", i)).unwrap();
+            theirs.add_task(i % 3, format!("Theirs {}", i)).unwrap();
+        }
+
+        assert_eq!(ours.merge(&theirs), 20);
+        assert_eq!(ours.total_tasks(), 40);
+
+        let tasks: Vec<&Task> = ours.columns.iter().flat_map(|column| &column.tasks).collect();
+        let uuids: std::collections::HashSet<&str> =
+            tasks.iter().filter_map(|task| task.uuid.as_deref()).collect();
+        assert_eq!(uuids.len(), 40);
+        let ids: std::collections::HashSet<usize> = tasks.iter().map(|task| task.id).collect();
+        assert_eq!(ids.len(), 40);
+
+        // Merging again finds every uuid already present
+        assert_eq!(ours.merge(&theirs), 0);
+        assert_eq!(ours.total_tasks(), 40);
+    }
+
+    #[test]
+    fn test_merge_maps_columns_by_name() {
+        let mut ours = Board::with_columns("Ours", vec!["Backlog".to_string(), "Done".to_string()]).unwrap();
+        ours.enable_stable_ids();
+        let mut theirs = Board::new("Theirs");
+        theirs.add_task(0, "Untracked").unwrap();
+        theirs.add_task(2, "Shipped").unwrap();
+
+        assert_eq!(ours.merge(&theirs), 2);
+        assert_eq!(ours.columns[0].tasks[0].title, "Untracked");
+        assert_eq!(ours.columns[1].tasks[0].title, "Shipped");
+        assert!(ours.columns[0].tasks[0].uuid.is_some());
+        let history = &ours.columns[0].tasks[0].column_history;
+        assert_eq!(history.last().unwrap().0, "Backlog");
+        assert_eq!(ours.columns[1].tasks[0].column_history.len(), 1);
+    }
+
+    #[test]
+    fn test_validate_valid_board() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Due").unwrap();
+        board.set_task_due_date(0, id, Some("2024-05-01 15:30".to_string())).unwrap();
+        board.add_task(2, "Done").unwrap();
+        assert_eq!(board.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_no_columns() {
+        let mut board = Board::new("Test");
+        board.columns.clear();
+        assert_eq!(board.validate(), Err(vec!["Board has no columns".to_string()]));
+    }
+
+    #[test]
+    fn test_validate_empty_column_name() {
+        let mut board = Board::new("Test");
+        board.columns[1].name = "  ".to_string();
+        assert_eq!(board.validate(), Err(vec!["Column 2 has an empty name".to_string()]));
+    }
+
+    #[test]
+    fn test_validate_duplicate_task_ids() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Original").unwrap();
+        let copy = board.columns[0].tasks[0].clone();
+        board.columns[1].tasks.push(copy);
+        assert_eq!(
+            board.validate(),
+            Err(vec![format!("Task id {} is used more than once", id)])
+        );
+    }
+
+    #[test]
+    fn test_validate_next_task_id_too_low() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "First").unwrap();
+        board.add_task(0, "Second").unwrap();
+        board.next_task_id = 2;
+        assert_eq!(
+            board.validate(),
+            Err(vec!["Task id 2 is not below the next task id (2)".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_due_date() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(1, "Someday").unwrap();
+        board.columns[1].tasks[0].due_date = Some("next week".to_string());
+        assert_eq!(
+            board.validate(),
+            Err(vec![format!("Task {} in 'In Progress' has an invalid due date 'next week'", id)])
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_start_date() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(0, "Later").unwrap();
+        board.columns[0].tasks[0].start_date = Some("2024-05-01 9am".to_string());
+        assert_eq!(
+            board.validate(),
+            Err(vec![format!("Task {} in 'To Do' has an invalid start date '2024-05-01 9am'", id)])
+        );
+
+        board.columns[0].tasks[0].start_date = Some("2024-05-01 09:00".to_string());
+        assert_eq!(board.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_all_problems() {
         let mut board = Board::new("Test");
         board.add_task(0, "Task").unwrap();
+        board.columns[0].name.clear();
+        board.columns[0].tasks[0].due_date = Some("soon".to_string());
+        board.next_task_id = 1;
+        assert_eq!(board.validate().unwrap_err().len(), 3);
+    }
 
-        // Try to update non-existent task
-        let result = board.update_task_title(0, 9999, "New Title");
-        assert!(result.is_err());
+    #[test]
+    fn test_tag_counts_with_overlapping_tags() {
+        let mut board = Board::new("Test");
+        let a = board.add_task(0, "A").unwrap();
+        let b = board.add_task(1, "B").unwrap();
+        let c = board.add_task(2, "C").unwrap();
+        board.add_task(0, "Untagged").unwrap();
+        for tag in ["ui", "bug"] {
+            board.add_task_tag(0, a, tag).unwrap();
+        }
+        for tag in ["bug", "docs", "ui"] {
+            board.add_task_tag(1, b, tag).unwrap();
+        }
+        for tag in ["bug", "Bug", "api"] {
+            board.add_task_tag(2, c, tag).unwrap();
+        }
+
+        assert_eq!(
+            board.tag_counts(),
+            vec![
+                ("bug".to_string(), 3),
+                ("ui".to_string(), 2),
+                ("Bug".to_string(), 1),
+                ("api".to_string(), 1),
+                ("docs".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_counts_empty_board() {
+        assert!(Board::new("Test").tag_counts().is_empty());
+    }
+
+    #[test]
+    fn test_archive_task() {
+        let mut board = Board::new("Test");
+        let id = board.add_task(2, "Done").unwrap();
+        assert!(board.archive_task(0, id).is_err());
+        assert!(board.archive_task(9, id).is_err());
+
+        board.archive_task(2, id).unwrap();
+        assert!(board.get_task(id).is_none());
+        assert_eq!(board.archived_tasks()[0].id, id);
+        assert!(board.archived_tasks()[0].archived_at.is_some());
+
+        let restored: Board = serde_json::from_str(&serde_json::to_string(&board).unwrap()).unwrap();
+        assert_eq!(restored.archived_tasks().len(), 1);
+    }
+
+    #[test]
+    fn test_purge_archived() {
+        let mut board = Board::new("Test");
+        for title in ["One", "Two", "Three"] {
+            let id = board.add_task(2, title).unwrap();
+            board.archive_task(2, id).unwrap();
+        }
+        board.add_task(0, "Still open").unwrap();
+
+        assert_eq!(board.purge_archived(), 3);
+        assert!(board.archived_tasks().is_empty());
+        assert_eq!(board.total_tasks(), 1);
+        assert_eq!(board.purge_archived(), 0);
+    }
+
+    #[test]
+    fn test_purge_archived_older_than() {
+        use crate::clock::{set_thread_clock, FixedClock};
+
+        let day = |d| chrono::NaiveDate::from_ymd_opt(2025, 1, d).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let mut board = Board::new("Test");
+        for (d, title) in [(1, "Old"), (15, "Recent"), (25, "New")] {
+            let _clock = set_thread_clock(FixedClock(day(d)));
+            let id = board.add_task(2, title).unwrap();
+            board.archive_task(2, id).unwrap();
+        }
+        let unreadable = board.add_task(2, "Unreadable").unwrap();
+        board.archive_task(2, unreadable).unwrap();
+        board.archived[3].archived_at = Some("yesterday".to_string());
+
+        let _clock = set_thread_clock(FixedClock(day(30)));
+        assert_eq!(board.purge_archived_older_than(10), 2);
+        let titles: Vec<&str> = board.archived_tasks().iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["New", "Unreadable"]);
+
+        assert_eq!(board.purge_archived_older_than(10), 0);
+        assert_eq!(board.purge_archived_older_than(0), 1);
+    }
+
+    #[test]
+    fn test_auto_archive_keeps_newest_tasks() {
+        use crate::clock::{set_thread_clock, FixedClock};
+
+        let day = |d| chrono::NaiveDate::from_ymd_opt(2025, 1, d).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let mut board = Board::new("Test");
+        board.set_auto_archive_after(2, Some(2)).unwrap();
+        // Added out of age order, so the oldest isn't simply the first in the column
+        for (d, title) in [(10, "Middle"), (1, "Oldest"), (20, "Newest"), (15, "Newer")] {
+            let _clock = set_thread_clock(FixedClock(day(d)));
+            board.add_task(2, title).unwrap();
+        }
+        board.add_task(0, "Unlimited column").unwrap();
+
+        assert_eq!(board.auto_archive(), 2);
+        let kept: Vec<&str> = board.columns[2].tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(kept, vec!["Newest", "Newer"]);
+        let archived: Vec<&str> = board.archived_tasks().iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(archived, vec!["Oldest", "Middle"]);
+        assert_eq!(board.columns[0].tasks.len(), 1);
+
+        // At the limit nothing more is archived
+        assert_eq!(board.auto_archive(), 0);
+    }
+
+    #[test]
+    fn test_auto_archive_off_by_default() {
+        let mut board = Board::new("Test");
+        for title in ["One", "Two", "Three"] {
+            board.add_task(2, title).unwrap();
+        }
+        assert_eq!(board.auto_archive(), 0);
+        assert!(board.set_auto_archive_after(5, Some(1)).is_err());
+
+        board.set_auto_archive_after(2, Some(0)).unwrap();
+        assert_eq!(board.auto_archive(), 3);
+        assert!(board.columns[2].tasks.is_empty());
+    }
+
+    #[test]
+    fn test_estimates_across_columns() {
+        let mut board = Board::new("Test");
+        for (col, title, estimate) in [
+            (0, "Small", Some(1)),
+            (0, "Unknown", None),
+            (1, "Large", Some(8)),
+            (2, "Done", Some(3)),
+            (2, "Done unestimated", None),
+        ] {
+            let id = board.add_task(col, title).unwrap();
+            board.task_mut(id).unwrap().estimate = estimate;
+        }
+
+        assert_eq!(board.total_estimate(), 12);
+        assert_eq!(board.remaining_estimate(), 9);
+        assert_eq!(
+            board.column_estimate_breakdown(),
+            vec![
+                ("To Do".to_string(), 1),
+                ("In Progress".to_string(), 8),
+                ("Done".to_string(), 3),
+            ]
+        );
+
+        board.set_done_column(Some(1)).unwrap();
+        assert_eq!(board.remaining_estimate(), 4);
+    }
+
+    #[test]
+    fn test_estimates_without_estimated_tasks() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Unestimated").unwrap();
+        assert_eq!(board.total_estimate(), 0);
+        assert_eq!(board.remaining_estimate(), 0);
+    }
+
+    #[test]
+    fn test_normalize_order_after_direct_edits() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "First").unwrap();
+        board.add_task(0, "Second").unwrap();
+        board.columns[0].tasks.swap(0, 1);
+
+        board.normalize_order();
+        let tasks: Vec<(&str, usize)> = board.columns[0]
+            .tasks
+            .iter()
+            .map(|t| (t.title.as_str(), t.order))
+            .collect();
+        assert_eq!(tasks, vec![("Second", 0), ("First", 1)]);
+    }
+
+    #[test]
+    fn test_board_is_empty() {
+        let mut board = Board::new("Test");
+        assert!(board.is_empty());
+
+        let task_id = board.add_task(2, "Only task").unwrap();
+        assert!(!board.is_empty());
+
+        board.columns[2].remove_task(task_id);
+        assert!(board.is_empty());
+    }
+
+    #[test]
+    fn test_tasks_with_tag() {
+        let mut board = Board::new("Test");
+        let a = board.add_task(0, "A").unwrap();
+        let b = board.add_task(2, "B").unwrap();
+        board.add_task(1, "Untagged").unwrap();
+        board.add_task_tag(0, a, "Bug").unwrap();
+        board.add_task_tag(2, b, "bug").unwrap();
+
+        let exact: Vec<(usize, usize)> = board.tasks_with_tag("bug").iter().map(|(t, c)| (t.id, *c)).collect();
+        assert_eq!(exact, vec![(b, 2)]);
+
+        let ci: Vec<(usize, usize)> = board.tasks_with_tag_ci("BUG").iter().map(|(t, c)| (t.id, *c)).collect();
+        assert_eq!(ci, vec![(a, 0), (b, 2)]);
+
+        assert!(board.tasks_with_tag("feature").is_empty());
+        assert!(board.tasks_with_tag_ci("feature").is_empty());
+    }
+
+    #[test]
+    fn test_move_tagged_to_done_counts_moved_tasks() {
+        let mut board = Board::new("Test");
+        let todo = board.add_task(0, "Todo").unwrap();
+        let doing = board.add_task(1, "Doing").unwrap();
+        let other = board.add_task(1, "Other feature").unwrap();
+        board.add_task(0, "Untagged").unwrap();
+        board.add_task_tag(0, todo, "feature-x").unwrap();
+        board.add_task_tag(1, doing, "feature-x").unwrap();
+        board.add_task_tag(1, other, "Feature-X").unwrap();
+
+        assert_eq!(board.move_tagged_to_done("feature-x").unwrap(), 2);
+        let done: Vec<usize> = board.columns[2].tasks.iter().map(|t| t.id).collect();
+        assert_eq!(done, vec![todo, doing]);
+        assert_eq!(board.columns[0].tasks.len(), 1);
+        assert_eq!(board.columns[1].tasks[0].id, other);
+        assert_eq!(board.move_tagged_to_done("missing").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_move_tagged_to_done_skips_done_tasks() {
+        let mut board = Board::new("Test");
+        let open = board.add_task(0, "Open").unwrap();
+        let finished = board.add_task(2, "Finished").unwrap();
+        board.add_task_tag(0, open, "release").unwrap();
+        board.add_task_tag(2, finished, "release").unwrap();
+        let history = board.columns[2].tasks[0].column_history.len();
+
+        assert_eq!(board.move_tagged_to_done("release").unwrap(), 1);
+        assert_eq!(board.columns[2].tasks.len(), 2);
+        assert_eq!(board.columns[2].tasks[0].id, finished);
+        assert_eq!(board.columns[2].tasks[0].column_history.len(), history);
+
+        // With several done columns, tasks in any of them stay put
+        board.columns[1].name = "Won't do".to_string();
+        board.done_column_names.push("won't do".to_string());
+        let dropped = board.add_task(1, "Dropped").unwrap();
+        board.add_task_tag(1, dropped, "release").unwrap();
+        assert_eq!(board.move_tagged_to_done("release").unwrap(), 0);
+        assert_eq!(board.columns[1].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_move_tagged_to_done_without_done_column() {
+        let mut board = Board::new("Test");
+        board.done_column_names = vec!["Shipped".to_string()];
+        assert!(board.move_tagged_to_done("release").is_err());
+    }
+
+    #[test]
+    fn test_with_columns_validation() {
+        let columns = vec!["Backlog".to_string(), "Doing".to_string(), "Done".to_string()];
+        let board = Board::with_columns("Test", columns).unwrap();
+        assert_eq!(board.columns.len(), 3);
+        assert_eq!(board.column_index_by_name("Doing"), Some(1));
+
+        assert!(Board::with_columns("Test", Vec::new()).is_err());
+
+        let err = Board::with_columns("Test", vec!["A".to_string(), "B".to_string(), "A".to_string()])
+            .unwrap_err();
+        assert!(err.contains("'A'"));
+    }
+
+    #[test]
+    fn test_fuzzy_search_ranks_closer_matches_first() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Fill out the long form now").unwrap();
+        board.add_task(1, "Fix login").unwrap();
+        board.add_task(2, "Unrelated").unwrap();
+
+        let results = board.fuzzy_search("flgn");
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].0, results[0].1), (1, 0));
+        assert_eq!((results[1].0, results[1].1), (0, 0));
+        assert!(results[0].2 > results[1].2);
+
+        assert!(board.fuzzy_search("zzz").is_empty());
+    }
+
+    #[test]
+    fn test_clone_as_gives_copied_tasks_fresh_uuids() {
+        let mut board = Board::new("Original");
+        board.enable_stable_ids();
+        board.add_task(0, "Shared").unwrap();
+        let archived = board.add_task(2, "Old").unwrap();
+        board.archive_task(2, archived).unwrap();
+
+        let copy = board.clone_as("Copy", true);
+        let uuid = |board: &Board| board.columns[0].tasks[0].uuid.clone().unwrap();
+        assert_ne!(uuid(&copy), uuid(&board));
+        assert_ne!(copy.archived_tasks()[0].uuid, board.archived_tasks()[0].uuid);
+        assert_eq!(copy.columns[0].tasks[0].id, board.columns[0].tasks[0].id);
+
+        // Merging the copy back in adds its tasks instead of skipping them
+        let mut merged = board.clone();
+        assert_eq!(merged.merge(&copy), 1);
+        assert_eq!(merged.columns[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_clone_as_structure_only_keeps_column_settings() {
+        let mut board = Board::with_columns("Template", vec!["Inbox".to_string(), "Doing".to_string(), "Shipped".to_string()]).unwrap();
+        board.description = "Release checklist".to_string();
+        board.set_wip_limit(1, Some(3)).unwrap();
+        let id = board.add_task(0, "Old work").unwrap();
+        board.add_task(0, "Archived work").unwrap();
+        board.archive_task(0, id).unwrap();
+
+        let copy = board.clone_as("Release 2", false);
+        assert_eq!(copy.name, "Release 2");
+        assert_eq!(copy.description, "Release checklist");
+        assert_eq!(copy.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Inbox", "Doing", "Shipped"]);
+        assert_eq!(copy.columns[1].wip_limit, Some(3));
+        assert!(copy.columns.iter().all(|c| c.tasks.is_empty()));
+        assert!(copy.archived_tasks().is_empty());
+
+        let mut copy = copy;
+        assert_eq!(copy.add_task(0, "Fresh").unwrap(), 1);
+        assert_eq!(board.name, "Template");
+        assert_eq!(board.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_set_columns_from_keeps_tasks_in_surviving_columns() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "Todo task").unwrap();
+        board.add_task(1, "Active task").unwrap();
+        board.add_task(2, "Done task").unwrap();
+        board.set_wip_limit(1, Some(2)).unwrap();
+
+        let names = vec!["Review".to_string(), "In Progress".to_string(), "Done".to_string()];
+        assert_eq!(board.set_columns_from(&names).unwrap(), 1);
+
+        assert_eq!(board.column_names(), names);
+        assert!(board.columns[0].tasks.is_empty());
+        assert_eq!(board.columns[1].tasks[0].title, "Active task");
+        assert_eq!(board.columns[1].wip_limit, Some(2));
+        assert_eq!(board.columns[2].tasks[0].title, "Done task");
+        assert_eq!(board.archived_tasks().len(), 1);
+        assert_eq!(board.archived_tasks()[0].title, "Todo task");
+        assert!(board.archived_tasks()[0].archived_at.is_some());
+    }
+
+    #[test]
+    fn test_set_columns_from_reorders_columns() {
+        let mut board = Board::new("Test");
+        board.add_task(0, "First").unwrap();
+        board.add_task(2, "Last").unwrap();
+        board.set_done_column(Some(2)).unwrap();
+
+        let names = vec!["Done".to_string(), "In Progress".to_string(), "To Do".to_string()];
+        assert_eq!(board.set_columns_from(&names).unwrap(), 0);
+
+        assert_eq!(board.column_names(), names);
+        assert_eq!(board.columns[0].tasks[0].title, "Last");
+        assert_eq!(board.columns[2].tasks[0].title, "First");
+        assert_eq!(board.done_column_index(), Some(0));
+
+        assert!(board.set_columns_from(&[]).is_err());
+        assert!(board.set_columns_from(&["A".to_string(), "A".to_string()]).is_err());
+        assert!(board.set_columns_from(&["A".to_string(), "a".to_string()]).is_err());
+        assert_eq!(board.column_names(), names);
+    }
+
+    #[test]
+    fn test_set_columns_from_matches_names_ignoring_case() {
+        let mut board = Board::new("Test");
+        board.add_task(2, "Shipped").unwrap();
+        board.set_done_column(Some(2)).unwrap();
+
+        let names = vec!["TO DO".to_string(), "in progress".to_string(), "done".to_string()];
+        assert_eq!(board.set_columns_from(&names).unwrap(), 0);
+        assert_eq!(board.column_names(), names);
+        assert_eq!(board.columns[2].tasks[0].title, "Shipped");
+        assert_eq!(board.done_column_index(), Some(2));
+    }
+
+    #[test]
+    fn test_column_load_and_wip_limits() {
+        let mut board = Board::new("Test");
+        board.set_wip_limit(1, Some(2)).unwrap();
+        assert!(board.set_wip_limit(9, Some(2)).is_err());
+        assert_eq!(board.column_load(0), (0, None));
+        assert_eq!(board.column_load(9), (0, None));
+
+        // Under the limit
+        board.add_task(1, "One").unwrap();
+        assert_eq!(board.column_load(1), (1, Some(2)));
+        assert!(board.over_wip_columns().is_empty());
+
+        // At the limit
+        board.add_task(1, "Two").unwrap();
+        assert_eq!(board.column_load(1), (2, Some(2)));
+        assert!(board.over_wip_columns().is_empty());
+
+        // Over the limit
+        board.add_task(1, "Three").unwrap();
+        assert_eq!(board.over_wip_columns(), vec![1]);
+
+        board.set_wip_limit(1, None).unwrap();
+        assert!(board.over_wip_columns().is_empty());
     }
 }
@@ -0,0 +1,182 @@
+//! Command-line argument handling for the Kanban TUI.
+
+use kanban_tui::Board;
+use serde_json::{json, Value};
+use std::io::Read;
+
+/// Parsed command-line options
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    /// Board to open instead of the stored active board
+    pub board: Option<String>,
+    /// Print the known board names and exit
+    pub list: bool,
+    /// Print a JSON summary of this board and exit
+    pub summary: Option<String>,
+    /// Open a throwaway board read from stdin instead of a stored one
+    pub stdin: bool,
+}
+
+pub const USAGE: &str = "Usage: kanban-tui [--board <name> | --stdin] [--list] [--summary <name>]";
+
+/// Parse command-line arguments (excluding the program name)
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--board" | "-b" => {
+                let name = args.next().ok_or("--board requires a board name")?;
+                parsed.board = Some(name);
+            }
+            "--list" | "-l" => parsed.list = true,
+            "--stdin" => parsed.stdin = true,
+            "--summary" => {
+                let name = args.next().ok_or("--summary requires a board name")?;
+                parsed.summary = Some(name);
+            }
+            _ => {
+                if let Some(name) = arg.strip_prefix("--board=") {
+                    parsed.board = Some(name.to_string());
+                } else {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
+            }
+        }
+    }
+
+    if parsed.stdin && parsed.board.is_some() {
+        return Err("--stdin can't be combined with --board".to_string());
+    }
+
+    Ok(parsed)
+}
+
+/// Read a board from JSON, e.g. piped in with `--stdin`
+pub fn read_board<R: Read>(mut reader: R) -> Result<Board, String> {
+    let mut json = String::new();
+    reader
+        .read_to_string(&mut json)
+        .map_err(|e| format!("Failed to read board: {}", e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Invalid board JSON: {}", e))
+}
+
+/// Decide which board to open on startup.
+///
+/// An explicitly requested board wins over the stored active board; with
+/// neither, the "default" board is used.
+pub fn resolve_board_name(requested: Option<&str>, active: Option<String>) -> Result<String, String> {
+    match requested {
+        Some(name) => {
+            let name = name.trim();
+            if name.is_empty() {
+                Err("Board name cannot be empty".to_string())
+            } else {
+                Ok(name.to_string())
+            }
+        }
+        None => Ok(active
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| "default".to_string())),
+    }
+}
+
+/// Build the JSON summary printed by `--summary`
+pub fn build_summary(name: &str, board: &Board) -> Value {
+    let columns: Vec<Value> = board
+        .columns
+        .iter()
+        .map(|column| json!({ "name": column.name, "count": column.tasks.len() }))
+        .collect();
+
+    json!({
+        "board": name,
+        "total": board.total_tasks(),
+        "columns": columns,
+        "overdue": board.overdue_count(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(args(&[])).unwrap(), Args::default());
+
+        let parsed = parse_args(args(&["--board", "work", "--list"])).unwrap();
+        assert_eq!(parsed.board.as_deref(), Some("work"));
+        assert!(parsed.list);
+
+        let parsed = parse_args(args(&["--board=home"])).unwrap();
+        assert_eq!(parsed.board.as_deref(), Some("home"));
+
+        let parsed = parse_args(args(&["--summary", "work"])).unwrap();
+        assert_eq!(parsed.summary.as_deref(), Some("work"));
+
+        let parsed = parse_args(args(&["--stdin"])).unwrap();
+        assert!(parsed.stdin);
+
+        assert!(parse_args(args(&["--stdin", "--board", "work"])).is_err());
+        assert!(parse_args(args(&["--board"])).is_err());
+        assert!(parse_args(args(&["--summary"])).is_err());
+        assert!(parse_args(args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_resolve_board_name() {
+        let active = Some("stored".to_string());
+
+        assert_eq!(resolve_board_name(Some("work"), active.clone()).unwrap(), "work");
+        assert_eq!(resolve_board_name(Some("  work "), None).unwrap(), "work");
+        assert_eq!(resolve_board_name(None, active).unwrap(), "stored");
+        assert_eq!(resolve_board_name(None, None).unwrap(), "default");
+
+        assert!(resolve_board_name(Some(""), None).is_err());
+        assert!(resolve_board_name(Some("   "), None).is_err());
+    }
+
+    #[test]
+    fn test_read_board() {
+        let mut board = Board::new("Piped");
+        board.add_task(1, "Doing").unwrap();
+        let json = serde_json::to_string(&board).unwrap();
+
+        let read = read_board(json.as_bytes()).unwrap();
+        assert_eq!(read.name, "Piped");
+        assert_eq!(read.columns[1].tasks[0].title, "Doing");
+
+        let err = read_board("{ not json".as_bytes()).unwrap_err();
+        assert!(err.starts_with("Invalid board JSON"));
+        assert!(read_board("".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_build_summary() {
+        let mut board = Board::new("Work");
+        let late = board.add_task(0, "Late").unwrap();
+        board.add_task(1, "Doing").unwrap();
+        board.set_task_due_date(0, late, Some("2000-01-01".to_string())).unwrap();
+
+        let summary = build_summary("work", &board);
+        assert_eq!(
+            summary,
+            json!({
+                "board": "work",
+                "total": 2,
+                "columns": [
+                    { "name": "To Do", "count": 1 },
+                    { "name": "In Progress", "count": 1 },
+                    { "name": "Done", "count": 0 },
+                ],
+                "overdue": 1,
+            })
+        );
+    }
+}
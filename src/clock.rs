@@ -0,0 +1,106 @@
+//! Injectable clock for timestamps and date-dependent logic.
+//!
+//! Library code asks [`now`] for the current time instead of calling
+//! `chrono::Local::now()` directly. By default this is the system clock, but a
+//! thread-local override can be installed (e.g. a [`FixedClock`] in tests) to make
+//! time-dependent behavior deterministic.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use kanban_tui::clock::{self, FixedClock};
+//!
+//! let frozen = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+//! let _guard = clock::set_thread_clock(FixedClock(frozen));
+//! assert_eq!(clock::now(), frozen);
+//! ```
+
+use chrono::{Local, NaiveDateTime};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Source of the current local date and time.
+pub trait Clock {
+    /// Returns the current local date and time
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+/// Clock frozen at a fixed point in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub NaiveDateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+thread_local! {
+    static CLOCK_OVERRIDE: RefCell<Option<Rc<dyn Clock>>> = RefCell::new(None);
+}
+
+/// Returns the current time from the thread's clock override, or the system clock
+pub fn now() -> NaiveDateTime {
+    CLOCK_OVERRIDE
+        .with(|clock| clock.borrow().as_ref().map(|c| c.now()))
+        .unwrap_or_else(|| SystemClock.now())
+}
+
+/// Overrides the clock for the current thread until the returned guard is dropped
+pub fn set_thread_clock(clock: impl Clock + 'static) -> ClockGuard {
+    let previous = CLOCK_OVERRIDE.with(|current| current.replace(Some(Rc::new(clock))));
+    ClockGuard { previous }
+}
+
+/// Restores the previous thread clock when dropped
+pub struct ClockGuard {
+    previous: Option<Rc<dyn Clock>>,
+}
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CLOCK_OVERRIDE.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_override_and_restore() {
+        {
+            let _outer = set_thread_clock(FixedClock(at(2024, 1, 1)));
+            assert_eq!(now(), at(2024, 1, 1));
+
+            {
+                let _inner = set_thread_clock(FixedClock(at(2025, 6, 1)));
+                assert_eq!(now(), at(2025, 6, 1));
+            }
+
+            assert_eq!(now(), at(2024, 1, 1));
+        }
+
+        assert_ne!(now(), at(2024, 1, 1));
+    }
+}
@@ -3,11 +3,51 @@
 use crate::Task;
 use serde::{Deserialize, Serialize};
 
+/// How a column keeps its tasks ordered
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SortMode {
+    /// Tasks stay in the order they were added or arranged
+    #[default]
+    Manual,
+    /// Highest priority first
+    Priority,
+    /// Earliest due first; tasks without a due date go last
+    DueDate,
+    /// Oldest first
+    Age,
+}
+
+impl SortMode {
+    /// The next mode, cycling Manual → Priority → DueDate → Age → Manual
+    pub fn next(&self) -> Self {
+        match self {
+            SortMode::Manual => SortMode::Priority,
+            SortMode::Priority => SortMode::DueDate,
+            SortMode::DueDate => SortMode::Age,
+            SortMode::Age => SortMode::Manual,
+        }
+    }
+}
+
+impl std::fmt::Display for SortMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortMode::Manual => write!(f, "manual"),
+            SortMode::Priority => write!(f, "priority"),
+            SortMode::DueDate => write!(f, "due date"),
+            SortMode::Age => write!(f, "age"),
+        }
+    }
+}
+
 /// Represents a column in the Kanban board.
 ///
 /// A column contains a name and a list of tasks. Common column names include
 /// "To Do", "In Progress", and "Done", but columns can have any name.
 ///
+/// The order of `tasks` is the column's order, top to bottom; it is saved and
+/// loaded exactly as is.
+///
 /// # Examples
 ///
 /// ```
@@ -26,6 +66,19 @@ use serde::{Deserialize, Serialize};
 pub struct Column {
     pub name: String,
     pub tasks: Vec<Task>,
+    /// Whether the column is shown as just its header
+    #[serde(default)]
+    pub collapsed: bool,
+    /// Maximum number of tasks the column should hold (work-in-progress limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<usize>,
+    /// Order tasks are kept in; reapplied on load and whenever a task is added
+    #[serde(default)]
+    pub sort_mode: SortMode,
+    /// Most tasks the column keeps; the oldest beyond this are archived when
+    /// the board is saved (see [`Board::auto_archive`](crate::Board::auto_archive))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archive_after: Option<usize>,
 }
 
 impl Column {
@@ -34,27 +87,122 @@ impl Column {
         Self {
             name: name.into(),
             tasks: Vec::new(),
+            collapsed: false,
+            wip_limit: None,
+            sort_mode: SortMode::Manual,
+            auto_archive_after: None,
         }
     }
 
-    /// Adds a task to the column
-    pub fn add_task(&mut self, task: Task) {
+    /// Adds a task to the end of the column, or where the sort mode puts it
+    pub fn add_task(&mut self, mut task: Task) {
+        task.order = self.tasks.len();
         self.tasks.push(task);
+        self.apply_sort();
+    }
+
+    /// Inserts a task at `index`, clamped to the end of the column.
+    ///
+    /// Sorted columns still place the task by their sort mode.
+    pub fn insert_task(&mut self, index: usize, task: Task) {
+        let index = index.min(self.tasks.len());
+        self.tasks.insert(index, task);
+        self.normalize_order();
+        self.apply_sort();
+    }
+
+    /// Reorders the tasks according to the column's sort mode.
+    ///
+    /// Sorting is stable, so tasks that compare equal keep their relative
+    /// order; `Manual` leaves the order untouched.
+    pub fn apply_sort(&mut self) {
+        match self.sort_mode {
+            SortMode::Manual => return,
+            SortMode::Priority => self.tasks.sort_by_key(|task| task.priority),
+            SortMode::DueDate => self.tasks.sort_by_cached_key(|task| {
+                let due = task.due_time();
+                (due.is_none(), due)
+            }),
+            SortMode::Age => self.sort_by_age(),
+        }
+        self.normalize_order();
+    }
+
+    /// Renumbers each task's `order` to match its position in the column
+    pub fn normalize_order(&mut self) {
+        for (position, task) in self.tasks.iter_mut().enumerate() {
+            task.order = position;
+        }
+    }
+
+    /// Returns true if the column holds no tasks
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Sorts the tasks oldest first.
+    ///
+    /// Tasks with an unparseable creation time go last; ties keep their order.
+    pub fn sort_by_age(&mut self) {
+        self.tasks.sort_by_cached_key(|task| {
+            let created = task.created_time();
+            (created.is_none(), created)
+        });
+        self.normalize_order();
+    }
+
+    /// Position of a task in this column, top to bottom
+    pub fn task_index(&self, task_id: usize) -> Option<usize> {
+        self.tasks.iter().position(|task| task.id == task_id)
+    }
+
+    /// Finds a task in this column by ID
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Column, Task};
+    ///
+    /// let mut column = Column::new("To Do");
+    /// column.add_task(Task::new(7, "Write docs"));
+    /// assert_eq!(column.task_by_id(7).unwrap().title, "Write docs");
+    /// assert!(column.task_by_id(8).is_none());
+    /// ```
+    pub fn task_by_id(&self, task_id: usize) -> Option<&Task> {
+        self.tasks.iter().find(|task| task.id == task_id)
+    }
+
+    /// Finds a task in this column by ID for editing
+    pub fn task_by_id_mut(&mut self, task_id: usize) -> Option<&mut Task> {
+        self.tasks.iter_mut().find(|task| task.id == task_id)
     }
 
     /// Removes a task by ID and returns it if found
     pub fn remove_task(&mut self, task_id: usize) -> Option<Task> {
-        if let Some(pos) = self.tasks.iter().position(|t| t.id == task_id) {
-            Some(self.tasks.remove(pos))
-        } else {
-            None
-        }
+        let pos = self.task_index(task_id)?;
+        let task = self.tasks.remove(pos);
+        self.normalize_order();
+        Some(task)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Priority;
+
+    #[test]
+    fn test_insert_task_clamps_index() {
+        let mut column = Column::new("To Do");
+        column.add_task(Task::new(1, "First"));
+        column.add_task(Task::new(2, "Last"));
+
+        column.insert_task(1, Task::new(3, "Middle"));
+        column.insert_task(99, Task::new(4, "End"));
+        let ids: Vec<usize> = column.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 3, 2, 4]);
+        assert_eq!(column.tasks[3].order, 3);
+    }
 
     #[test]
     fn test_column_add_remove_task() {
@@ -69,4 +217,130 @@ mod tests {
         assert_eq!(removed.unwrap(), task);
         assert_eq!(column.tasks.len(), 0);
     }
+
+    #[test]
+    fn test_task_by_id() {
+        let mut column = Column::new("To Do");
+        column.add_task(Task::new(1, "First"));
+        column.add_task(Task::new(5, "Second"));
+        column.add_task(Task::new(3, "Third"));
+
+        assert_eq!(column.task_by_id(5).map(|t| t.title.as_str()), Some("Second"));
+        assert_eq!(column.task_by_id(3).map(|t| t.title.as_str()), Some("Third"));
+        assert!(column.task_by_id(2).is_none());
+
+        assert_eq!(column.task_index(5), Some(1));
+        assert_eq!(column.task_index(3), Some(2));
+        assert_eq!(column.task_index(2), None);
+    }
+
+    #[test]
+    fn test_task_by_id_mut_edits_in_place() {
+        let mut column = Column::new("To Do");
+        column.add_task(Task::new(1, "First"));
+        column.add_task(Task::new(2, "Second"));
+
+        column.task_by_id_mut(2).unwrap().title = "Renamed".to_string();
+        assert_eq!(column.tasks[1].title, "Renamed");
+        assert_eq!(column.tasks[0].title, "First");
+        assert!(column.task_by_id_mut(9).is_none());
+    }
+
+    #[test]
+    fn test_sort_by_age_puts_oldest_first_and_unparseable_last() {
+        let mut column = Column::new("To Do");
+        for (id, created_at) in [
+            (1, "2024-03-15 09:00:00"),
+            (2, "garbage"),
+            (3, "2024-01-02 08:00:00"),
+            (4, "2024-03-14 23:59:59"),
+        ] {
+            let mut task = Task::new(id, format!("Task {}", id));
+            task.created_at = created_at.to_string();
+            column.add_task(task);
+        }
+
+        column.sort_by_age();
+        let ids: Vec<usize> = column.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![3, 4, 1, 2]);
+    }
+
+    fn column_with(sort_mode: SortMode) -> Column {
+        let mut column = Column::new("To Do");
+        column.sort_mode = sort_mode;
+        column
+    }
+
+    #[test]
+    fn test_manual_sort_keeps_insertion_order() {
+        let mut column = column_with(SortMode::Manual);
+        column.add_task(Task::builder(1, "Low").priority(Priority::Low).build());
+        column.add_task(Task::builder(2, "High").priority(Priority::High).build());
+        let ids: Vec<usize> = column.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_added_tasks_follow_priority_sort() {
+        let mut column = column_with(SortMode::Priority);
+        column.add_task(Task::builder(1, "Low").priority(Priority::Low).build());
+        column.add_task(Task::builder(2, "None").build());
+        column.add_task(Task::builder(3, "High").priority(Priority::High).build());
+        column.add_task(Task::builder(4, "Low again").priority(Priority::Low).build());
+
+        let ids: Vec<usize> = column.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![3, 1, 4, 2]);
+        let orders: Vec<usize> = column.tasks.iter().map(|t| t.order).collect();
+        assert_eq!(orders, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_added_tasks_follow_due_date_sort() {
+        let mut column = column_with(SortMode::DueDate);
+        column.add_task(Task::builder(1, "Undated").build());
+        column.add_task(Task::builder(2, "Friday").due_date("2024-03-15").build());
+        column.add_task(Task::builder(3, "Friday noon").due_date("2024-03-15 12:00").build());
+        column.add_task(Task::builder(4, "Monday").due_date("2024-03-11").build());
+
+        let ids: Vec<usize> = column.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_mode_cycles() {
+        let mut mode = SortMode::default();
+        let mut seen = vec![mode];
+        for _ in 0..3 {
+            mode = mode.next();
+            seen.push(mode);
+        }
+        assert_eq!(seen, vec![SortMode::Manual, SortMode::Priority, SortMode::DueDate, SortMode::Age]);
+        assert_eq!(mode.next(), SortMode::Manual);
+    }
+
+    #[test]
+    fn test_order_follows_position() {
+        let mut column = Column::new("To Do");
+        for id in 1..=3 {
+            column.add_task(Task::new(id, "Task"));
+        }
+        let orders: Vec<usize> = column.tasks.iter().map(|t| t.order).collect();
+        assert_eq!(orders, vec![0, 1, 2]);
+
+        column.remove_task(1);
+        let orders: Vec<(usize, usize)> = column.tasks.iter().map(|t| (t.id, t.order)).collect();
+        assert_eq!(orders, vec![(2, 0), (3, 1)]);
+    }
+
+    #[test]
+    fn test_column_is_empty() {
+        let mut column = Column::new("To Do");
+        assert!(column.is_empty());
+
+        column.add_task(Task::new(1, "Test"));
+        assert!(!column.is_empty());
+
+        column.remove_task(1);
+        assert!(column.is_empty());
+    }
 }
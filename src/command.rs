@@ -0,0 +1,176 @@
+//! Serializable board operations for scripting and replay.
+//!
+//! A [`Command`] names one edit to a board together with its arguments, so a
+//! sequence of edits can be written down (e.g. as JSON), stored, and replayed
+//! with [`Board::apply`] without going through the TUI.
+
+use crate::board::Board;
+use crate::task::Priority;
+use serde::{Deserialize, Serialize};
+
+/// A single edit to a board, dispatched by [`Board::apply`]
+///
+/// Commands serialize with their name in a `command` field, e.g.
+/// `{"command": "add_task", "column": 0, "title": "Write docs"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    /// Add a task to the end of a column; it gets the board's next task id
+    AddTask { column: usize, title: String },
+    MoveTask { from: usize, to: usize, task_id: usize },
+    DeleteTask { column: usize, task_id: usize },
+    ArchiveTask { column: usize, task_id: usize },
+    UpdateTitle { column: usize, task_id: usize, title: String },
+    SetDescription { column: usize, task_id: usize, description: String },
+    SetPriority { column: usize, task_id: usize, priority: Priority },
+    AddTag { column: usize, task_id: usize, tag: String },
+    /// Set or, with `None`, clear a task's due date
+    SetDueDate { column: usize, task_id: usize, due_date: Option<String> },
+    SetWipLimit { column: usize, limit: Option<usize> },
+}
+
+impl Board {
+    /// Applies a command by calling the matching board method.
+    ///
+    /// Replaying the same commands on equal boards gives equal results, since
+    /// new tasks take their ids from the board.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the underlying method, e.g. when a column index
+    /// is out of bounds or the task isn't in the given column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Board, Command};
+    ///
+    /// let mut board = Board::new("Project");
+    /// board.apply(Command::AddTask { column: 0, title: "Ship it".to_string() }).unwrap();
+    /// let task_id = board.columns[0].tasks[0].id;
+    /// board.apply(Command::MoveTask { from: 0, to: 2, task_id }).unwrap();
+    /// assert_eq!(board.columns[2].tasks[0].title, "Ship it");
+    /// ```
+    pub fn apply(&mut self, command: Command) -> Result<(), String> {
+        match command {
+            Command::AddTask { column, title } => self.add_task(column, title).map(|_| ()),
+            Command::MoveTask { from, to, task_id } => self.move_task(from, to, task_id),
+            Command::DeleteTask { column, task_id } => self
+                .columns
+                .get_mut(column)
+                .ok_or("Column index out of bounds")?
+                .remove_task(task_id)
+                .map(|_| ())
+                .ok_or_else(|| "Task not found in column".to_string()),
+            Command::ArchiveTask { column, task_id } => self.archive_task(column, task_id),
+            Command::UpdateTitle { column, task_id, title } => {
+                self.update_task_title(column, task_id, title).map(|_| ())
+            }
+            Command::SetDescription { column, task_id, description } => {
+                self.update_task_description(column, task_id, description)
+            }
+            Command::SetPriority { column, task_id, priority } => {
+                self.set_task_priority(column, task_id, priority)
+            }
+            Command::AddTag { column, task_id, tag } => self.add_task_tag(column, task_id, tag),
+            Command::SetDueDate { column, task_id, due_date } => {
+                self.set_task_due_date(column, task_id, due_date)
+            }
+            Command::SetWipLimit { column, limit } => self.set_wip_limit(column, limit),
+        }
+    }
+
+    /// Applies commands in order, stopping at the first one that fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the position of the failed command with its error; commands
+    /// before it stay applied.
+    pub fn apply_all(
+        &mut self,
+        commands: impl IntoIterator<Item = Command>,
+    ) -> Result<(), (usize, String)> {
+        for (position, command) in commands.into_iter().enumerate() {
+            self.apply(command).map_err(|e| (position, e))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_command_sequence() {
+        let mut board = Board::new("Test");
+        board
+            .apply_all([
+                Command::AddTask { column: 0, title: "Write docs".to_string() },
+                Command::AddTask { column: 0, title: "Fix crash".to_string() },
+                Command::AddTask { column: 0, title: "Scratch".to_string() },
+                Command::SetPriority { column: 0, task_id: 2, priority: Priority::High },
+                Command::AddTag { column: 0, task_id: 2, tag: "bug".to_string() },
+                Command::MoveTask { from: 0, to: 1, task_id: 2 },
+                Command::UpdateTitle { column: 1, task_id: 2, title: "Fix startup crash".to_string() },
+                Command::SetDueDate { column: 0, task_id: 1, due_date: Some("2030-01-01".to_string()) },
+                Command::DeleteTask { column: 0, task_id: 3 },
+                Command::SetWipLimit { column: 1, limit: Some(2) },
+            ])
+            .unwrap();
+
+        assert_eq!(board.columns[0].tasks.len(), 1);
+        assert_eq!(board.columns[0].tasks[0].due_date.as_deref(), Some("2030-01-01"));
+        let fixed = &board.columns[1].tasks[0];
+        assert_eq!(fixed.title, "Fix startup crash");
+        assert_eq!(fixed.priority, Priority::High);
+        assert_eq!(fixed.tags, vec!["bug"]);
+        assert_eq!(board.columns[1].wip_limit, Some(2));
+    }
+
+    #[test]
+    fn test_apply_all_stops_at_first_error() {
+        let mut board = Board::new("Test");
+        let result = board.apply_all([
+            Command::AddTask { column: 0, title: "Kept".to_string() },
+            Command::MoveTask { from: 0, to: 1, task_id: 99 },
+            Command::AddTask { column: 0, title: "Never added".to_string() },
+        ]);
+
+        assert_eq!(result, Err((1, "Task not found in source column".to_string())));
+        assert_eq!(board.columns[0].tasks.len(), 1);
+        assert!(board.apply(Command::DeleteTask { column: 7, task_id: 1 }).is_err());
+    }
+
+    #[test]
+    fn test_replay_gives_the_same_board() {
+        let commands = vec![
+            Command::AddTask { column: 0, title: "One".to_string() },
+            Command::AddTask { column: 1, title: "Two".to_string() },
+            Command::ArchiveTask { column: 0, task_id: 1 },
+            Command::SetDescription { column: 1, task_id: 2, description: "Details".to_string() },
+        ];
+
+        let mut first = Board::new("Test");
+        let mut second = Board::new("Test");
+        first.apply_all(commands.clone()).unwrap();
+        second.apply_all(commands).unwrap();
+        assert_eq!(first.archived_tasks().len(), 1);
+        assert_eq!(first.columns[1].tasks[0].id, second.columns[1].tasks[0].id);
+        assert_eq!(second.columns[1].tasks[0].description.as_deref(), Some("Details"));
+    }
+
+    #[test]
+    fn test_commands_round_trip_through_json() {
+        let json = r#"[
+            {"command": "add_task", "column": 0, "title": "Write docs"},
+            {"command": "set_priority", "column": 0, "task_id": 1, "priority": "High"},
+            {"command": "set_due_date", "column": 0, "task_id": 1, "due_date": null}
+        ]"#;
+        let commands: Vec<Command> = serde_json::from_str(json).unwrap();
+        assert_eq!(commands[0], Command::AddTask { column: 0, title: "Write docs".to_string() });
+
+        let encoded = serde_json::to_string(&commands).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Command>>(&encoded).unwrap(), commands);
+    }
+}
@@ -0,0 +1,94 @@
+//! Registry of named commands shared by the command palette and help screen.
+
+use crate::app::App;
+
+/// A user-facing action with its palette name and normal-mode key
+pub struct CommandSpec {
+    /// Name typed in the command palette
+    pub name: &'static str,
+    /// Normal-mode key(s) bound to the action
+    pub keys: &'static str,
+    pub description: &'static str,
+    pub run: fn(&mut App),
+}
+
+/// Every command, in the order shown on the help screen
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec { name: "new-task", keys: "n", description: "New task (↑/↓ recall recent titles)", run: App::start_creating },
+    CommandSpec { name: "capture", keys: "Ctrl+N", description: "Capture a task from any mode, then carry on", run: App::start_quick_capture },
+    CommandSpec { name: "edit-title", keys: "e", description: "Edit title", run: App::start_editing },
+    CommandSpec { name: "view", keys: "i / Enter", description: "View task details", run: App::start_viewing },
+    CommandSpec { name: "edit-description", keys: "D", description: "Edit description", run: App::start_editing_description },
+    CommandSpec { name: "cycle-priority", keys: "p", description: "Cycle priority", run: App::cycle_priority },
+    CommandSpec { name: "set-filtered-priority", keys: "P", description: "Set the priority of every filtered task", run: App::start_setting_filtered_priority },
+    CommandSpec { name: "add-tag", keys: "t", description: "Add tag", run: App::start_adding_tag },
+    CommandSpec { name: "set-due-date", keys: "@", description: "Set due date (YYYY-MM-DD, optionally HH:MM)", run: App::start_setting_due_date },
+    CommandSpec { name: "set-start-date", keys: ">", description: "Set start date (YYYY-MM-DD, optionally HH:MM)", run: App::start_setting_start_date },
+    CommandSpec { name: "tag-column", keys: "T", description: "Tag every task in the column (-tag removes)", run: App::start_tagging_column },
+    CommandSpec { name: "complete-tagged", keys: "F", description: "Move every task with a tag to done", run: App::start_completing_tag },
+    CommandSpec { name: "previous-column", keys: "h / ←", description: "Previous column", run: App::previous_column },
+    CommandSpec { name: "next-column", keys: "l / →", description: "Next column", run: App::next_column },
+    CommandSpec { name: "next-task", keys: "j / ↓", description: "Next task", run: App::next_task },
+    CommandSpec { name: "previous-task", keys: "k / ↑", description: "Previous task", run: App::previous_task },
+    CommandSpec { name: "next-overdue", keys: "O", description: "Jump to the next overdue task", run: App::select_next_overdue },
+    CommandSpec { name: "move-left", keys: "H", description: "Move task left", run: App::move_task_left },
+    CommandSpec { name: "move-right", keys: "L", description: "Move task right", run: App::move_task_right },
+    CommandSpec { name: "toggle-done", keys: "Space", description: "Move task to done (or a done task back to the first column)", run: App::toggle_selected_done },
+    CommandSpec { name: "delete", keys: "d", description: "Delete task", run: App::delete_selected_task },
+    CommandSpec { name: "archive", keys: "a", description: "Move task to the board's archive", run: App::archive_selected_task },
+    CommandSpec { name: "purge-archive", keys: "X", description: "Permanently delete archived tasks", run: App::request_purge_archive },
+    CommandSpec { name: "collapse-column", keys: "C", description: "Collapse/expand column", run: App::toggle_column_collapsed },
+    CommandSpec { name: "focus-column", keys: "z", description: "Show only the selected column (toggle)", run: App::toggle_focus_mode },
+    CommandSpec { name: "sort-oldest-first", keys: "o", description: "Keep column sorted oldest first", run: App::sort_column_by_age },
+    CommandSpec { name: "cycle-sort", keys: "s", description: "Cycle column sort: manual/priority/due date/age", run: App::cycle_sort_mode },
+    CommandSpec { name: "merge-duplicates", keys: "M", description: "Merge tasks with duplicate titles", run: App::request_merge_duplicates },
+    CommandSpec { name: "yank", keys: "Ctrl+Y", description: "Yank task", run: App::yank_task },
+    CommandSpec { name: "paste", keys: "Ctrl+P", description: "Paste a copy of the yanked task", run: App::paste_task },
+    CommandSpec { name: "undo", keys: "u", description: "Undo", run: App::undo },
+    CommandSpec { name: "write", keys: "w", description: "Write the board to disk now", run: App::save_now },
+    CommandSpec { name: "filter", keys: "/", description: "Filter tasks", run: App::start_filtering },
+    CommandSpec { name: "switch-board", keys: "b", description: "Select board", run: App::start_board_selection },
+    CommandSpec { name: "new-board", keys: "B", description: "New board", run: App::start_creating_board },
+    CommandSpec { name: "edit-board-description", keys: "E", description: "Edit the board description", run: App::start_editing_board_description },
+    CommandSpec { name: "previous-board", keys: "Tab", description: "Switch to the previous board", run: App::toggle_previous_board },
+    CommandSpec { name: "command-palette", keys: ":", description: "Run a command by name", run: App::start_command_palette },
+    CommandSpec { name: "help", keys: "?", description: "Toggle this help", run: App::show_help },
+    CommandSpec { name: "quit", keys: "q", description: "Quit", run: App::request_quit },
+];
+
+/// Commands whose name starts with `query`, ignoring case and surrounding whitespace
+pub fn matching(query: &str) -> Vec<&'static CommandSpec> {
+    let query = query.trim().to_lowercase();
+    COMMANDS
+        .iter()
+        .filter(|command| command.name.starts_with(&query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(commands: Vec<&CommandSpec>) -> Vec<&str> {
+        commands.iter().map(|command| command.name).collect()
+    }
+
+    #[test]
+    fn test_matching_filters_by_prefix() {
+        assert_eq!(names(matching("move")), vec!["move-left", "move-right"]);
+        assert_eq!(names(matching("  QUIT ")), vec!["quit"]);
+        assert!(matching("bogus").is_empty());
+        assert_eq!(matching("").len(), COMMANDS.len());
+    }
+
+    #[test]
+    fn test_command_names_are_unique() {
+        for (idx, command) in COMMANDS.iter().enumerate() {
+            assert!(
+                COMMANDS[idx + 1..].iter().all(|other| other.name != command.name),
+                "duplicate command name {}",
+                command.name
+            );
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! User-configurable settings for the Kanban TUI.
+//!
+//! Settings are read once at startup from `config.json` next to the boards
+//! directory (e.g. `~/.config/kanban-tui/config.json`). The file groups
+//! settings into sections, and anything left out keeps its default:
+//!
+//! ```json
+//! {
+//!   "theme": { "tag_colors": { "bug": "red" } },
+//!   "display": { "card_style": "plain", "status_bar_position": "top" },
+//!   "behavior": { "auto_save": false, "new_task_column": { "fixed_name": "Inbox" } }
+//! }
+//! ```
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Which column newly created tasks are added to
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewTaskTarget {
+    /// The currently selected column
+    #[default]
+    Selected,
+    /// A fixed column by position
+    FixedIndex(usize),
+    /// A fixed column by name, e.g. an "Inbox"
+    FixedName(String),
+}
+
+/// Where the status bar sits relative to the columns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+    Top,
+    #[default]
+    Bottom,
+}
+
+/// How task cards are drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardStyle {
+    /// Each card is framed by a rounded box
+    #[default]
+    Boxed,
+    /// Cards are drawn without a frame for a denser view
+    Plain,
+}
+
+/// How tasks whose start date hasn't arrived yet are shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotStartedStyle {
+    /// Drawn like any other task
+    Show,
+    /// Drawn in a muted color
+    #[default]
+    Dim,
+    /// Left off the board until the start date
+    Hide,
+}
+
+/// How the selected task, board or command is highlighted
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SelectionStyle {
+    /// Background color name or hex value; `None` leaves the background alone
+    pub bg: Option<String>,
+    /// Text color name or hex value; `None` keeps the text color
+    pub fg: Option<String>,
+    pub bold: bool,
+    pub underline: bool,
+    /// Swap the text and background colors instead of filling the background
+    pub reverse: bool,
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        Self {
+            bg: Some("cyan".to_string()),
+            fg: Some("black".to_string()),
+            bold: true,
+            underline: false,
+            reverse: false,
+        }
+    }
+}
+
+/// Application settings
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Maximum number of undo snapshots kept (0 disables undo)
+    pub undo_limit: usize,
+    /// Tag name to color name (e.g. "bug" -> "red"); unmapped tags render cyan
+    pub tag_colors: HashMap<String, String>,
+    /// Longest task title accepted, in characters (0 = unlimited)
+    pub max_title_len: usize,
+    /// Ask before destructive actions such as deleting a board
+    pub confirm_destructive: bool,
+    /// Column new tasks land in; unknown columns fall back to the selected one
+    pub new_task_column: NewTaskTarget,
+    /// Narrowest a column is drawn before the board scrolls sideways (0 = always fit all)
+    pub min_column_width: u16,
+    /// Show each task's age (time since creation) on its card
+    pub show_age: bool,
+    /// Save after every change; when off, boards are only written with `w`
+    pub auto_save: bool,
+    /// Moving past the last task or column jumps back to the first (and vice versa)
+    pub wrap_navigation: bool,
+    /// Whether the status bar is drawn above or below the columns
+    pub status_bar_position: Position,
+    /// Warn when a due date is entered more than this many days in the past (0 = never)
+    pub stale_due_days: u32,
+    /// Blank lines drawn between cards (0 = cards touch)
+    pub card_spacing: u16,
+    /// Whether cards are framed by a box
+    pub card_style: CardStyle,
+    /// Pin a summary line (count, WIP limit, sort mode) under each column's title
+    pub column_summary: bool,
+    /// Draw without colors, marking state with text instead (set by `NO_COLOR`)
+    pub no_color: bool,
+    /// Highlight for the selected task and the selection in popups
+    pub selection_style: SelectionStyle,
+    /// Wrap long titles onto more lines of a taller card instead of truncating them
+    pub wrap_titles: bool,
+    /// How tasks that can't be started yet are drawn
+    pub not_started: NotStartedStyle,
+    /// Open the board selector at startup when there is more than one board
+    pub start_in_selector: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            undo_limit: 20,
+            tag_colors: HashMap::new(),
+            max_title_len: 200,
+            confirm_destructive: true,
+            new_task_column: NewTaskTarget::default(),
+            min_column_width: 20,
+            show_age: false,
+            auto_save: true,
+            wrap_navigation: true,
+            status_bar_position: Position::default(),
+            stale_due_days: 365,
+            card_spacing: 1,
+            card_style: CardStyle::default(),
+            column_summary: false,
+            no_color: false,
+            selection_style: SelectionStyle::default(),
+            wrap_titles: false,
+            not_started: NotStartedStyle::default(),
+            start_in_selector: false,
+        }
+    }
+}
+
+/// Colors, in the `theme` section of the config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeSection {
+    tag_colors: Option<HashMap<String, String>>,
+}
+
+/// Layout and card appearance, in the `display` section of the config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DisplaySection {
+    min_column_width: Option<u16>,
+    show_age: Option<bool>,
+    status_bar_position: Option<Position>,
+    card_spacing: Option<u16>,
+    card_style: Option<CardStyle>,
+    column_summary: Option<bool>,
+    selection_style: Option<SelectionStyle>,
+    wrap_titles: Option<bool>,
+    not_started: Option<NotStartedStyle>,
+}
+
+/// Editing and saving, in the `behavior` section of the config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BehaviorSection {
+    undo_limit: Option<usize>,
+    max_title_len: Option<usize>,
+    confirm_destructive: Option<bool>,
+    new_task_column: Option<NewTaskTarget>,
+    auto_save: Option<bool>,
+    wrap_navigation: Option<bool>,
+    stale_due_days: Option<u32>,
+    start_in_selector: Option<bool>,
+}
+
+/// Layout of `config.json`; unknown sections and keys are ignored
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    theme: ThemeSection,
+    display: DisplaySection,
+    behavior: BehaviorSection,
+}
+
+impl Config {
+    /// Default location of the config file, next to the boards directory
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("kanban-tui").join("config.json"))
+    }
+
+    /// Whether the `NO_COLOR` convention (<https://no-color.org>) asks for colorless output
+    pub fn no_color_from_env() -> bool {
+        std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+    }
+
+    /// Read settings from `path`, using the defaults if the file doesn't exist
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Parse settings from the contents of a config file; missing values keep their defaults
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file: ConfigFile =
+            serde_json::from_str(json).map_err(|e| format!("Invalid config: {}", e))?;
+        let mut config = Self::default();
+
+        let ThemeSection { tag_colors } = file.theme;
+        if let Some(tag_colors) = tag_colors {
+            config.tag_colors = tag_colors;
+        }
+
+        let display = file.display;
+        if let Some(width) = display.min_column_width {
+            config.min_column_width = width;
+        }
+        if let Some(show_age) = display.show_age {
+            config.show_age = show_age;
+        }
+        if let Some(position) = display.status_bar_position {
+            config.status_bar_position = position;
+        }
+        if let Some(spacing) = display.card_spacing {
+            config.card_spacing = spacing;
+        }
+        if let Some(style) = display.card_style {
+            config.card_style = style;
+        }
+        if let Some(summary) = display.column_summary {
+            config.column_summary = summary;
+        }
+        if let Some(style) = display.selection_style {
+            config.selection_style = style;
+        }
+        if let Some(wrap) = display.wrap_titles {
+            config.wrap_titles = wrap;
+        }
+        if let Some(not_started) = display.not_started {
+            config.not_started = not_started;
+        }
+
+        let behavior = file.behavior;
+        if let Some(limit) = behavior.undo_limit {
+            config.undo_limit = limit;
+        }
+        if let Some(len) = behavior.max_title_len {
+            config.max_title_len = len;
+        }
+        if let Some(confirm) = behavior.confirm_destructive {
+            config.confirm_destructive = confirm;
+        }
+        if let Some(target) = behavior.new_task_column {
+            config.new_task_column = target;
+        }
+        if let Some(auto_save) = behavior.auto_save {
+            config.auto_save = auto_save;
+        }
+        if let Some(wrap) = behavior.wrap_navigation {
+            config.wrap_navigation = wrap;
+        }
+        if let Some(days) = behavior.stale_due_days {
+            config.stale_due_days = days;
+        }
+        if let Some(start_in_selector) = behavior.start_in_selector {
+            config.start_in_selector = start_in_selector;
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_falls_back_to_defaults() {
+        let config = Config::from_json(
+            r#"{
+                "theme": { "tag_colors": { "bug": "red" } },
+                "display": { "card_style": "plain" },
+                "behavior": { "auto_save": false, "new_task_column": { "fixed_name": "Inbox" } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.tag_colors.get("bug").map(String::as_str), Some("red"));
+        assert_eq!(config.card_style, CardStyle::Plain);
+        assert!(!config.auto_save);
+        assert_eq!(config.new_task_column, NewTaskTarget::FixedName("Inbox".to_string()));
+
+        let defaults = Config::default();
+        assert_eq!(config.undo_limit, defaults.undo_limit);
+        assert_eq!(config.min_column_width, defaults.min_column_width);
+        assert_eq!(config.status_bar_position, Position::Bottom);
+        assert_eq!(config.card_spacing, defaults.card_spacing);
+        assert_eq!(config.wrap_navigation, defaults.wrap_navigation);
+        assert_eq!(config.stale_due_days, defaults.stale_due_days);
+    }
+
+    #[test]
+    fn test_partial_selection_style_keeps_other_defaults() {
+        let config = Config::from_json(
+            r#"{ "display": { "selection_style": { "bg": null, "reverse": true } } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.selection_style,
+            SelectionStyle { bg: None, reverse: true, ..SelectionStyle::default() }
+        );
+    }
+
+    #[test]
+    fn test_empty_config_is_default() {
+        let config = Config::from_json("{}").unwrap();
+        let defaults = Config::default();
+        assert_eq!(config.undo_limit, defaults.undo_limit);
+        assert_eq!(config.card_style, defaults.card_style);
+        assert_eq!(config.new_task_column, defaults.new_task_column);
+    }
+
+    #[test]
+    fn test_invalid_config_is_an_error() {
+        assert!(Config::from_json(r#"{ "display": { "card_style": "fancy" } }"#).is_err());
+        assert!(Config::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file_uses_defaults() {
+        let path = std::env::temp_dir().join(format!("kanban-config-missing-{}.json", std::process::id()));
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.undo_limit, Config::default().undo_limit);
+    }
+
+    #[test]
+    fn test_load_reads_file() {
+        let path = std::env::temp_dir().join(format!("kanban-config-{}.json", std::process::id()));
+        fs::write(&path, r#"{ "display": { "status_bar_position": "top" } }"#).unwrap();
+        let config = Config::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(config.status_bar_position, Position::Top);
+    }
+}
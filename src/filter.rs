@@ -0,0 +1,206 @@
+//! Task filtering shared by search and filter features.
+
+use crate::{Priority, Task};
+
+/// A combined predicate over tasks.
+///
+/// Every criterion that is set must match for a task to pass; an empty filter
+/// matches every task.
+///
+/// # Examples
+///
+/// ```
+/// use kanban_tui::{Priority, Task, TaskFilter};
+///
+/// let mut task = Task::new(1, "Fix login bug");
+/// task.add_tag("backend");
+/// task.set_priority(Priority::High);
+///
+/// let filter = TaskFilter {
+///     query: Some("login".to_string()),
+///     tag: Some("backend".to_string()),
+///     min_priority: Some(Priority::Medium),
+///     ..TaskFilter::default()
+/// };
+/// assert!(filter.matches(&task));
+///
+/// // The same filter can be written as text
+/// assert_eq!(TaskFilter::parse("login #backend !medium"), filter);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskFilter {
+    /// Case-insensitive text matched against title, description, and tags
+    pub query: Option<String>,
+    /// Tag the task must carry (case-insensitive)
+    pub tag: Option<String>,
+    /// Lowest priority to include, e.g. `Medium` keeps High and Medium tasks
+    pub min_priority: Option<Priority>,
+    /// Only include tasks whose due date has passed
+    pub overdue_only: bool,
+}
+
+impl TaskFilter {
+    /// Parses a filter from text.
+    ///
+    /// Words starting with `#` select a tag, `!high`/`!medium`/`!low`/`!none` set
+    /// the priority threshold, and `is:overdue` keeps only overdue tasks. The
+    /// remaining words form the text query.
+    pub fn parse(input: &str) -> Self {
+        let mut filter = TaskFilter::default();
+        let mut words = Vec::new();
+
+        for word in input.split_whitespace() {
+            if let Some(tag) = word.strip_prefix('#').filter(|t| !t.is_empty()) {
+                filter.tag = Some(tag.to_string());
+            } else if word.eq_ignore_ascii_case("is:overdue") {
+                filter.overdue_only = true;
+            } else if let Some(priority) = word.strip_prefix('!').and_then(parse_priority) {
+                filter.min_priority = Some(priority);
+            } else {
+                words.push(word);
+            }
+        }
+
+        if !words.is_empty() {
+            filter.query = Some(words.join(" "));
+        }
+        filter
+    }
+
+    /// Returns true if any criterion is set
+    pub fn is_active(&self) -> bool {
+        *self != TaskFilter::default()
+    }
+
+    /// Checks whether a task passes every criterion of the filter
+    pub fn matches(&self, task: &Task) -> bool {
+        if let Some(query) = &self.query {
+            if !task.matches(query) {
+                return false;
+            }
+        }
+
+        if let Some(tag) = &self.tag {
+            if !task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                return false;
+            }
+        }
+
+        if let Some(min_priority) = self.min_priority {
+            // Priorities are ordered High < Medium < Low < None
+            if task.priority > min_priority {
+                return false;
+            }
+        }
+
+        if self.overdue_only && !task.is_overdue() {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl std::fmt::Display for TaskFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(query) = &self.query {
+            parts.push(query.clone());
+        }
+        if let Some(tag) = &self.tag {
+            parts.push(format!("#{}", tag));
+        }
+        if let Some(priority) = self.min_priority {
+            parts.push(format!("!{}", priority.to_string().to_lowercase()));
+        }
+        if self.overdue_only {
+            parts.push("is:overdue".to_string());
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+fn parse_priority(name: &str) -> Option<Priority> {
+    Priority::all()
+        .into_iter()
+        .find(|p| p.to_string().eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{self, FixedClock};
+    use chrono::NaiveDate;
+
+    fn sample_task() -> Task {
+        let mut task = Task::with_description(1, "Fix login page", "Users see a blank screen");
+        task.add_tag("Bug");
+        task.set_priority(Priority::Medium);
+        task
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = TaskFilter::default();
+        assert!(!filter.is_active());
+        assert!(filter.matches(&sample_task()));
+        assert!(filter.matches(&Task::new(2, "Anything")));
+    }
+
+    #[test]
+    fn test_query_criterion() {
+        let task = sample_task();
+        assert!(TaskFilter::parse("LOGIN").matches(&task));
+        assert!(TaskFilter::parse("blank screen").matches(&task));
+        assert!(!TaskFilter::parse("signup").matches(&task));
+    }
+
+    #[test]
+    fn test_tag_criterion() {
+        let task = sample_task();
+        assert!(TaskFilter::parse("#bug").matches(&task));
+        assert!(!TaskFilter::parse("#feature").matches(&task));
+    }
+
+    #[test]
+    fn test_priority_threshold_criterion() {
+        let task = sample_task();
+        assert!(TaskFilter::parse("!low").matches(&task));
+        assert!(TaskFilter::parse("!medium").matches(&task));
+        assert!(!TaskFilter::parse("!high").matches(&task));
+    }
+
+    #[test]
+    fn test_overdue_criterion() {
+        let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let _clock = clock::set_thread_clock(FixedClock(now));
+
+        let mut overdue = sample_task();
+        overdue.set_due_date(Some("2024-05-01".to_string()));
+        let mut upcoming = sample_task();
+        upcoming.set_due_date(Some("2024-07-01".to_string()));
+
+        let filter = TaskFilter::parse("is:overdue");
+        assert!(filter.matches(&overdue));
+        assert!(!filter.matches(&upcoming));
+        assert!(!filter.matches(&sample_task()));
+    }
+
+    #[test]
+    fn test_combined_criteria() {
+        let task = sample_task();
+        assert!(TaskFilter::parse("login #bug !medium").matches(&task));
+        assert!(!TaskFilter::parse("login #bug !high").matches(&task));
+        assert!(!TaskFilter::parse("signup #bug").matches(&task));
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let filter = TaskFilter::parse("fix  login #bug !HIGH is:overdue");
+        assert_eq!(filter.query.as_deref(), Some("fix login"));
+        assert_eq!(filter.tag.as_deref(), Some("bug"));
+        assert_eq!(filter.min_priority, Some(Priority::High));
+        assert!(filter.overdue_only);
+        assert_eq!(TaskFilter::parse(&filter.to_string()), filter);
+    }
+}
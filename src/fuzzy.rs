@@ -0,0 +1,83 @@
+//! Subsequence-based fuzzy matching for search.
+
+/// Points for each matched character
+const MATCH_SCORE: i64 = 16;
+/// Extra points when a match directly follows the previous one
+const CONSECUTIVE_BONUS: i64 = 16;
+/// Extra points when a match starts a word
+const WORD_START_BONUS: i64 = 8;
+/// Points lost for each skipped character between matches
+const GAP_PENALTY: i64 = 1;
+
+/// Scores how well `query` matches `candidate` as a case-insensitive subsequence.
+///
+/// Returns `None` unless every query character appears in order. Tighter,
+/// word-aligned matches score higher, so "flgn" ranks "Fix login" above
+/// "Fill out the long form now". Whitespace in the query is ignored.
+pub(crate) fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0;
+    let mut query_pos = 0;
+    let mut last_match: Option<usize> = None;
+    let mut previous: Option<char> = None;
+
+    for (pos, c) in candidate.chars().enumerate() {
+        if query_pos == query.len() {
+            break;
+        }
+
+        let is_word_start = previous.is_none_or(|p| !p.is_alphanumeric());
+        previous = Some(c);
+        if !c.to_lowercase().eq(std::iter::once(query[query_pos])) {
+            continue;
+        }
+
+        score += MATCH_SCORE;
+        if is_word_start {
+            score += WORD_START_BONUS;
+        }
+        match last_match {
+            Some(last) if last + 1 == pos => score += CONSECUTIVE_BONUS,
+            Some(last) => score -= GAP_PENALTY * (pos - last - 1) as i64,
+            None => {}
+        }
+
+        last_match = Some(pos);
+        query_pos += 1;
+    }
+
+    (query_pos == query.len()).then_some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_required() {
+        assert!(fuzzy_score("flgn", "Fix login").is_some());
+        assert!(fuzzy_score("FLGN", "fix login").is_some());
+        assert!(fuzzy_score("ngl", "Fix login").is_none());
+        assert!(fuzzy_score("xyz", "Fix login").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_closer_match_scores_higher() {
+        let tight = fuzzy_score("login", "Fix login").unwrap();
+        let loose = fuzzy_score("login", "Load old gin bottles").unwrap();
+        assert!(tight > loose, "{} <= {}", tight, loose);
+
+        let aligned = fuzzy_score("flgn", "Fix login").unwrap();
+        let scattered = fuzzy_score("flgn", "Fill out the long form now").unwrap();
+        assert!(aligned > scattered, "{} <= {}", aligned, scattered);
+    }
+}
@@ -1,33 +1,79 @@
 //! Keyboard input handling for the Kanban TUI.
 
-use crate::app::{App, InputMode};
+use crate::app::{App, ConfirmAction, InputMode};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle keyboard events based on current input mode
 pub fn handle_key_event(app: &mut App, key: KeyEvent) -> bool {
-    match app.input_mode {
+    // Status messages only live until the next key press
+    app.status_message = None;
+
+    // Quick capture works from every mode and comes back to it afterwards
+    if key.code == KeyCode::Char('n') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.start_quick_capture();
+        return false;
+    }
+
+    // Ctrl+C quits from every mode, asking first if there are unsaved changes
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.request_quit();
+        app.surface_pending_confirmation();
+        return app.should_quit;
+    }
+
+    let quit = match app.input_mode {
         InputMode::Normal => handle_normal_mode(app, key),
         InputMode::Creating => handle_creating_mode(app, key),
+        InputMode::Capturing => handle_capturing_mode(app, key),
         InputMode::Editing => handle_editing_mode(app, key),
         InputMode::Viewing => handle_viewing_mode(app, key),
         InputMode::EditingDescription => handle_editing_description_mode(app, key),
         InputMode::AddingTag => handle_adding_tag_mode(app, key),
         InputMode::SelectingBoard => handle_selecting_board_mode(app, key),
         InputMode::CreatingBoard => handle_creating_board_mode(app, key),
-    }
+        InputMode::Confirming => handle_confirming_mode(app, key),
+        InputMode::Filtering => handle_filtering_mode(app, key),
+        InputMode::TaggingColumn => handle_tagging_column_mode(app, key),
+        InputMode::CompletingTag => handle_completing_tag_mode(app, key),
+        InputMode::SettingDueDate => handle_setting_due_date_mode(app, key),
+        InputMode::SettingStartDate => handle_setting_start_date_mode(app, key),
+        InputMode::SettingFilteredPriority => handle_setting_filtered_priority_mode(app, key),
+        InputMode::EditingBoardDescription => handle_editing_board_description_mode(app, key),
+        InputMode::CommandPalette => handle_command_palette_mode(app, key),
+        InputMode::Help => handle_help_mode(app, key),
+    };
+
+    app.surface_pending_confirmation();
+    quit || app.should_quit
 }
 
 fn handle_normal_mode(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
-        KeyCode::Char('q') => return true, // Signal to quit
+        KeyCode::Char('q') => app.request_quit(),
+        KeyCode::Char('w') => app.save_now(),
         KeyCode::Char('n') => app.start_creating(),
         KeyCode::Char('e') => app.start_editing(),
         KeyCode::Char('i') | KeyCode::Enter => app.start_viewing(),
+        KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => app.yank_task(),
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => app.paste_task(),
         KeyCode::Char('p') => app.cycle_priority(),
+        KeyCode::Char('P') => app.start_setting_filtered_priority(),
         KeyCode::Char('D') => app.start_editing_description(),
         KeyCode::Char('t') => app.start_adding_tag(),
+        KeyCode::Char('@') => app.start_setting_due_date(),
+        KeyCode::Char('>') => app.start_setting_start_date(),
+        KeyCode::Char('T') => app.start_tagging_column(),
+        KeyCode::Char('F') => app.start_completing_tag(),
+        KeyCode::Char('C') => app.toggle_column_collapsed(),
+        KeyCode::Char('z') => app.toggle_focus_mode(),
+        KeyCode::Char('o') => app.sort_column_by_age(),
+        KeyCode::Char('O') => app.select_next_overdue(),
+        KeyCode::Char('s') => app.cycle_sort_mode(),
+        KeyCode::Char('M') => app.request_merge_duplicates(),
         KeyCode::Char('b') => app.start_board_selection(),
         KeyCode::Char('B') => app.start_creating_board(),
+        KeyCode::Char('E') => app.start_editing_board_description(),
+        KeyCode::Tab => app.toggle_previous_board(),
         KeyCode::Char('h') | KeyCode::Left => {
             if key.modifiers.contains(KeyModifiers::SHIFT) {
                 app.move_task_left();
@@ -47,6 +93,14 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Char('j') | KeyCode::Down => app.next_task(),
         KeyCode::Char('k') | KeyCode::Up => app.previous_task(),
         KeyCode::Char('d') => app.delete_selected_task(),
+        KeyCode::Char(' ') => app.toggle_selected_done(),
+        KeyCode::Char('a') => app.archive_selected_task(),
+        KeyCode::Char('X') => app.request_purge_archive(),
+        KeyCode::Char('u') => app.undo(),
+        KeyCode::Char('/') => app.start_filtering(),
+        KeyCode::Char('?') => app.show_help(),
+        KeyCode::Char(':') => app.start_command_palette(),
+        KeyCode::Esc => app.clear_filter(),
         _ => {}
     }
     false
@@ -56,10 +110,22 @@ fn handle_creating_mode(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
         KeyCode::Enter => app.create_task(),
         KeyCode::Esc => app.cancel_creating(),
+        KeyCode::Up => app.recall_previous_title(),
+        KeyCode::Down => app.recall_next_title(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_capturing_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.finish_quick_capture(),
+        KeyCode::Esc => app.cancel_quick_capture(),
         KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                return true; // Quit on Ctrl+C
-            }
             app.handle_char_input(c);
         }
         KeyCode::Backspace => app.handle_backspace(),
@@ -73,9 +139,6 @@ fn handle_editing_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Enter => app.save_edit(),
         KeyCode::Esc => app.cancel_editing(),
         KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                return true; // Quit on Ctrl+C
-            }
             app.handle_char_input(c);
         }
         KeyCode::Backspace => app.handle_backspace(),
@@ -89,6 +152,8 @@ fn handle_viewing_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Esc | KeyCode::Char('i') | KeyCode::Enter | KeyCode::Char('q') => {
             app.stop_viewing();
         }
+        KeyCode::Char('x') => app.clear_description(),
+        KeyCode::Char('X') => app.clear_due_date(),
         _ => {}
     }
     false
@@ -99,9 +164,73 @@ fn handle_editing_description_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Enter => app.save_description(),
         KeyCode::Esc => app.cancel_editing_description(),
         KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                return true; // Quit on Ctrl+C
-            }
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_setting_due_date_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.save_due_date(),
+        KeyCode::Esc => app.cancel_setting_due_date(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_setting_start_date_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.save_start_date(),
+        KeyCode::Esc => app.cancel_setting_start_date(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_setting_filtered_priority_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.set_filtered_priority(),
+        KeyCode::Esc => app.cancel_setting_filtered_priority(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_editing_board_description_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.save_board_description(),
+        KeyCode::Esc => app.cancel_editing_board_description(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_command_palette_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.run_selected_command(),
+        KeyCode::Esc => app.cancel_command_palette(),
+        KeyCode::Down => app.next_command(),
+        KeyCode::Up => app.previous_command(),
+        KeyCode::Char(c) => {
             app.handle_char_input(c);
         }
         KeyCode::Backspace => app.handle_backspace(),
@@ -115,9 +244,6 @@ fn handle_adding_tag_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Enter => app.add_tag(),
         KeyCode::Esc => app.cancel_adding_tag(),
         KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                return true; // Quit on Ctrl+C
-            }
             app.handle_char_input(c);
         }
         KeyCode::Backspace => app.handle_backspace(),
@@ -133,6 +259,9 @@ fn handle_selecting_board_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Char('j') | KeyCode::Down => app.next_board_in_list(),
         KeyCode::Char('k') | KeyCode::Up => app.previous_board_in_list(),
         KeyCode::Char('d') => app.delete_selected_board(),
+        KeyCode::Char('a') => app.toggle_selected_board_archived(),
+        KeyCode::Char('A') => app.toggle_archived_view(),
+        KeyCode::Char('c') => app.request_copy_columns(),
         KeyCode::Char('n') | KeyCode::Char('B') => {
             app.cancel_board_selection();
             app.start_creating_board();
@@ -147,9 +276,6 @@ fn handle_creating_board_mode(app: &mut App, key: KeyEvent) -> bool {
         KeyCode::Enter => app.create_new_board(),
         KeyCode::Esc => app.cancel_creating_board(),
         KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                return true; // Quit on Ctrl+C
-            }
             app.handle_char_input(c);
         }
         KeyCode::Backspace => app.handle_backspace(),
@@ -157,3 +283,83 @@ fn handle_creating_board_mode(app: &mut App, key: KeyEvent) -> bool {
     }
     false
 }
+
+fn handle_confirming_mode(app: &mut App, key: KeyEvent) -> bool {
+    if app.pending_confirmation == Some(ConfirmAction::ExternalChange) {
+        match key.code {
+            KeyCode::Char('o') | KeyCode::Char('O') => app.confirm(),
+            KeyCode::Char('r') | KeyCode::Char('R') => app.reload_after_external_change(),
+            KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Esc => app.cancel_confirmation(),
+            _ => {}
+        }
+        return false;
+    }
+
+    if matches!(app.pending_confirmation, Some(ConfirmAction::UnsavedChanges(_))) {
+        match key.code {
+            KeyCode::Char('s') | KeyCode::Char('S') => app.confirm(),
+            KeyCode::Char('d') | KeyCode::Char('D') => app.discard_unsaved_changes(),
+            KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Esc => app.cancel_confirmation(),
+            _ => {}
+        }
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm(),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel_confirmation(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_filtering_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.apply_filter(),
+        KeyCode::Esc => app.cancel_filtering(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_tagging_column_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.tag_column(),
+        KeyCode::Esc => app.cancel_adding_tag(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_completing_tag_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Enter => app.request_complete_tagged(),
+        KeyCode::Esc => app.cancel_adding_tag(),
+        KeyCode::Char(c) => {
+            app.handle_char_input(c);
+        }
+        KeyCode::Backspace => app.handle_backspace(),
+        _ => {}
+    }
+    false
+}
+
+fn handle_help_mode(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') | KeyCode::Enter => app.close_help(),
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_help(1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_help(-1),
+        KeyCode::PageDown => app.scroll_help(10),
+        KeyCode::PageUp => app.scroll_help(-10),
+        _ => {}
+    }
+    false
+}
@@ -42,7 +42,7 @@
 //!
 //! // Create or load a board
 //! let storage = Storage::new().expect("Failed to initialize storage");
-//! let mut board = storage.load()
+//! let mut board = storage.active_board()
 //!     .ok()
 //!     .flatten()
 //!     .unwrap_or_else(|| Board::new("My Board".to_string()));
@@ -51,7 +51,7 @@
 //! board.add_task(0, "New task".to_string()).unwrap();
 //!
 //! // Save the board
-//! storage.save(&board).expect("Failed to save board");
+//! storage.save_active(&board).expect("Failed to save board");
 //! ```
 //!
 //! ## Architecture
@@ -62,16 +62,31 @@
 //! - [`Column`]: A vertical section of the board containing tasks
 //! - [`Task`]: An individual work item with metadata
 //!
+//! Edits can also be described as data: a [`Command`] names one operation, and
+//! [`Board::apply`] runs it, so scripts and tests can replay a sequence of edits
+//! (see `examples/headless.rs`).
+//!
 //! The [`storage`] module provides persistence functionality using JSON files
-//! stored in platform-specific configuration directories.
+//! stored in platform-specific configuration directories, and the [`clock`] module
+//! provides an injectable source of the current time.
 
 mod task;
 mod column;
 mod board;
+mod command;
+mod filter;
+mod fuzzy;
+mod overlay;
+mod view;
+mod stable_id;
 
+pub mod clock;
 pub mod storage;
 
 // Re-export main types
-pub use task::{Task, Priority};
-pub use column::Column;
+pub use task::{Task, TaskBuilder, Priority};
+pub use column::{Column, SortMode};
 pub use board::Board;
+pub use command::Command;
+pub use filter::TaskFilter;
+pub use view::{BoardView, ColumnView, TaskView};
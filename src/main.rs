@@ -1,17 +1,71 @@
 mod app;
+mod cli;
+mod commands;
+mod config;
 mod input;
 mod ui;
 
 use app::App;
+use config::Config;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use kanban_tui::storage::Storage;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::path::PathBuf;
+use std::process;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = cli::parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, cli::USAGE);
+        process::exit(2);
+    });
+
+    if let Some(name) = &args.summary {
+        let Some(board) = Storage::new()?.load_board(name)? else {
+            eprintln!("Error: board '{}' not found", name);
+            process::exit(1);
+        };
+        println!("{}", cli::build_summary(name, &board));
+        return Ok(());
+    }
+
+    if args.list {
+        for name in Storage::new()?.list_boards()? {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    // Create app state before touching the terminal so errors print cleanly
+    let config = match Config::default_path() {
+        Some(path) => Config::load(&path),
+        None => Ok(Config::default()),
+    }
+    .map(|mut config| {
+        config.no_color |= Config::no_color_from_env();
+        config
+    });
+    let app = config.and_then(|config| {
+        if args.stdin {
+            cli::read_board(io::stdin().lock()).and_then(|board| App::ephemeral(board, config))
+        } else {
+            App::new(args.board.as_deref(), config)
+        }
+    });
+    let mut app = app.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+
+    // Boards read from stdin only live in a temporary directory, removed however we exit
+    let _temp_dir = args
+        .stdin
+        .then(|| RemoveDirOnDrop(app.storage.storage_location().to_path_buf()));
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -19,9 +73,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app state
-    let mut app = App::new();
-
     // Run the application
     let res = run_app(&mut terminal, &mut app);
 
@@ -41,11 +92,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Removes a directory when dropped, ignoring failures
+struct RemoveDirOnDrop(PathBuf);
+
+impl Drop for RemoveDirOnDrop {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> Result<(), Box<dyn std::error::Error>> {
     loop {
+        app.expire_notifications();
         terminal.draw(|f| ui::ui(f, app))?;
 
         // Handle input
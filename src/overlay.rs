@@ -0,0 +1,123 @@
+//! Per-user overrides layered on top of a shared board.
+
+use crate::{Board, Priority};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Local changes to a shared board, keyed by task id.
+///
+/// Only a task's column and priority can be overridden; the shared board
+/// itself is never written.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BoardOverlay {
+    #[serde(default)]
+    pub tasks: BTreeMap<usize, TaskOverride>,
+}
+
+/// Overridden fields of a single task
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TaskOverride {
+    /// Name of the column the task is in locally
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+}
+
+impl BoardOverlay {
+    /// Records how `local` differs from `base` for tasks that exist in both
+    pub fn diff(base: &Board, local: &Board) -> Self {
+        let mut tasks = BTreeMap::new();
+
+        for column in &local.columns {
+            for task in &column.tasks {
+                let Some((base_task, base_column)) = base.get_task(task.id) else {
+                    continue;
+                };
+
+                let task_override = TaskOverride {
+                    column: (base.columns[base_column].name != column.name)
+                        .then(|| column.name.clone()),
+                    priority: (base_task.priority != task.priority).then_some(task.priority),
+                };
+                if task_override != TaskOverride::default() {
+                    tasks.insert(task.id, task_override);
+                }
+            }
+        }
+
+        Self { tasks }
+    }
+
+    /// Returns `base` with the overrides applied.
+    ///
+    /// Overrides for tasks or columns that no longer exist in the base are ignored.
+    pub fn apply(&self, base: &Board) -> Board {
+        let mut board = base.clone();
+
+        for (&task_id, task_override) in &self.tasks {
+            let Some((_, from_column)) = board.get_task(task_id) else {
+                continue;
+            };
+
+            let mut column_index = from_column;
+            if let Some(to_column) = task_override
+                .column
+                .as_deref()
+                .and_then(|name| board.column_index_by_name(name))
+            {
+                if to_column != from_column {
+                    if let Some(task) = board.columns[from_column].remove_task(task_id) {
+                        board.columns[to_column].add_task(task);
+                        column_index = to_column;
+                    }
+                }
+            }
+
+            if let Some(priority) = task_override.priority {
+                if let Some(task) = board.columns[column_index].task_by_id_mut(task_id) {
+                    task.priority = priority;
+                    task.priority_level = None;
+                }
+            }
+        }
+
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_records_column_and_priority_changes() {
+        let mut base = Board::new("Shared");
+        let moved = base.add_task(0, "Moved").unwrap();
+        let untouched = base.add_task(0, "Untouched").unwrap();
+
+        let mut local = base.clone();
+        local.move_task(0, 1, moved).unwrap();
+        local.cycle_task_priority(1, moved).unwrap();
+        local.add_task(0, "Local only").unwrap();
+
+        let overlay = BoardOverlay::diff(&base, &local);
+        assert_eq!(overlay.tasks.len(), 1);
+        assert!(!overlay.tasks.contains_key(&untouched));
+        assert_eq!(overlay.tasks[&moved].column.as_deref(), Some("In Progress"));
+        assert_eq!(overlay.tasks[&moved].priority, Some(Priority::Low));
+    }
+
+    #[test]
+    fn test_apply_ignores_stale_overrides() {
+        let mut base = Board::new("Shared");
+        let id = base.add_task(0, "Task").unwrap();
+
+        let mut overlay = BoardOverlay::default();
+        overlay.tasks.insert(id, TaskOverride { column: Some("Archive".to_string()), priority: None });
+        overlay.tasks.insert(999, TaskOverride { column: Some("Done".to_string()), priority: None });
+
+        let board = overlay.apply(&base);
+        assert_eq!(board.get_task(id).unwrap().1, 0);
+    }
+}
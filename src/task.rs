@@ -1,18 +1,63 @@
 //! Task and Priority types for Kanban boards.
 
+use crate::clock;
+use crate::fuzzy;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 
 /// Priority level for tasks
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Priority {
     // Ordered from highest to lowest priority (High > Medium > Low > None)
     High,
     Medium,
     Low,
+    #[default]
     None,
 }
 
 impl Priority {
+    /// All priority levels in descending order (High to None)
+    pub fn all() -> [Priority; 4] {
+        [Priority::High, Priority::Medium, Priority::Low, Priority::None]
+    }
+
+    /// Map a level of an `n`-level custom priority scheme onto the closest built-in
+    /// priority, used for colors and symbols
+    pub fn for_level(level: usize, level_count: usize) -> Self {
+        let all = Self::all();
+        if level_count == 0 {
+            return Priority::None;
+        }
+        all[(level.min(level_count - 1) * all.len() / level_count).min(all.len() - 1)]
+    }
+
+    /// Level of an `n`-level custom priority scheme this priority maps to; the
+    /// inverse of [`Priority::for_level`], with `None` on the lowest level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Priority;
+    ///
+    /// assert_eq!(Priority::High.to_level(5), 0);
+    /// assert_eq!(Priority::None.to_level(5), 4);
+    /// assert_eq!(Priority::for_level(Priority::Medium.to_level(5), 5), Priority::Medium);
+    /// ```
+    pub fn to_level(&self, level_count: usize) -> usize {
+        let last = level_count.saturating_sub(1);
+        match self {
+            Priority::None => last,
+            // The first level for_level maps onto this priority
+            _ => (self.index() * level_count).div_ceil(Self::all().len()).min(last),
+        }
+    }
+
+    /// Position of this priority in [`Priority::all`]
+    pub fn index(&self) -> usize {
+        Self::all().iter().position(|p| p == self).unwrap_or(0)
+    }
+
     /// Get the next priority level (cycles through all levels)
     pub fn next(&self) -> Self {
         match self {
@@ -34,12 +79,6 @@ impl Priority {
     }
 }
 
-impl Default for Priority {
-    fn default() -> Self {
-        Priority::None
-    }
-}
-
 impl std::fmt::Display for Priority {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -51,6 +90,36 @@ impl std::fmt::Display for Priority {
     }
 }
 
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    /// Parses a priority name (`high`, `medium`/`med`, `low`, `none`), ignoring
+    /// case and surrounding whitespace, or a card symbol (`!!`, `!`, `·`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Priority;
+    ///
+    /// assert_eq!("High".parse(), Ok(Priority::High));
+    /// assert_eq!("med".parse(), Ok(Priority::Medium));
+    /// assert_eq!("!!".parse(), Ok(Priority::High));
+    /// assert!("urgent".parse::<Priority>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "high" | "!!" => Ok(Priority::High),
+            "medium" | "med" | "!" => Ok(Priority::Medium),
+            "low" | "·" => Ok(Priority::Low),
+            "none" => Ok(Priority::None),
+            _ => Err(format!(
+                "Unknown priority '{}' (expected high, medium, low or none)",
+                s.trim()
+            )),
+        }
+    }
+}
+
 /// Represents a single task in the Kanban board.
 ///
 /// A task contains a unique ID, title, optional description, priority level,
@@ -84,17 +153,67 @@ pub struct Task {
     pub priority: Priority,
     #[serde(default)]
     pub tags: Vec<String>,
+    /// People following the task without being assigned to it
+    #[serde(default)]
+    pub watchers: Vec<String>,
     #[serde(default = "current_timestamp")]
     pub created_at: String,
     #[serde(default = "current_timestamp")]
     pub updated_at: String,
     #[serde(default)]
     pub due_date: Option<String>,
+    /// When work on the task can begin, in the same formats as `due_date`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Index into the board's custom priority scheme; overrides `priority` there
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_level: Option<usize>,
+    /// Column name and entry timestamp for every column the task has been in.
+    ///
+    /// Grows by one entry per move and is never trimmed, so it doubles as a
+    /// full audit trail for cycle-time reporting.
+    #[serde(default)]
+    pub column_history: Vec<(String, String)>,
+    /// Position within its column.
+    ///
+    /// The order of the column's task list is canonical; this mirrors it so
+    /// consumers reading tasks on their own still know where they belong.
+    /// Column and board methods keep it in step, and
+    /// [`Board::normalize_order`](crate::Board::normalize_order) renumbers
+    /// after editing task lists directly.
+    #[serde(default)]
+    pub order: usize,
+    /// Stable identifier that survives merges and copies between boards.
+    ///
+    /// Only assigned on boards that opt in with
+    /// [`Board::enable_stable_ids`](crate::Board::enable_stable_ids); the
+    /// numeric `id` is still what the UI shows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// Story points, for capacity and burndown totals; unestimated tasks count as zero
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<u32>,
+    /// When the task was moved to the board's archive (see [`Board::archive_task`](crate::Board::archive_task))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// Fields this version doesn't know about, e.g. written by a newer
+    /// version; kept as-is and written back on save so they aren't lost
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Format used for due dates
+pub const DUE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Format used for due dates with a time of day
+pub const DUE_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Format used for created/updated and column-entry timestamps
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
 /// Helper function for serde default
 fn current_timestamp() -> String {
-    chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+    clock::now().format(TIMESTAMP_FORMAT).to_string()
 }
 
 impl Task {
@@ -115,15 +234,25 @@ impl Task {
     /// assert!(task.tags.is_empty());
     /// ```
     pub fn new(id: usize, title: impl Into<String>) -> Self {
+        let now = current_timestamp();
         Self {
             id,
             title: title.into(),
             description: None,
             priority: Priority::None,
             tags: Vec::new(),
-            created_at: current_timestamp(),
-            updated_at: current_timestamp(),
+            watchers: Vec::new(),
+            created_at: now.clone(),
+            updated_at: now,
             due_date: None,
+            start_date: None,
+            priority_level: None,
+            column_history: Vec::new(),
+            order: 0,
+            uuid: None,
+            estimate: None,
+            archived_at: None,
+            extra: serde_json::Map::new(),
         }
     }
 
@@ -143,14 +272,41 @@ impl Task {
     /// ```
     pub fn with_description(id: usize, title: impl Into<String>, description: impl Into<String>) -> Self {
         Self {
+            description: Some(description.into()),
+            ..Self::new(id, title)
+        }
+    }
+
+    /// Starts building a task with several fields set at once.
+    ///
+    /// Unlike calling setters one by one, the built task's `created_at` and
+    /// `updated_at` are the same single timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::{Priority, Task};
+    ///
+    /// let task = Task::builder(1, "Ship release")
+    ///     .description("Tag and publish")
+    ///     .priority(Priority::High)
+    ///     .tag("release")
+    ///     .due_date("2024-05-01")
+    ///     .build();
+    ///
+    /// assert_eq!(task.priority, Priority::High);
+    /// assert_eq!(task.tags, vec!["release"]);
+    /// assert_eq!(task.created_at, task.updated_at);
+    /// ```
+    pub fn builder(id: usize, title: impl Into<String>) -> TaskBuilder {
+        TaskBuilder {
             id,
             title: title.into(),
-            description: Some(description.into()),
+            description: None,
             priority: Priority::None,
             tags: Vec::new(),
-            created_at: current_timestamp(),
-            updated_at: current_timestamp(),
             due_date: None,
+            start_date: None,
         }
     }
 
@@ -168,6 +324,7 @@ impl Task {
     /// Sets the priority of the task
     pub fn set_priority(&mut self, priority: Priority) {
         self.priority = priority;
+        self.priority_level = None;
         self.updated_at = current_timestamp();
     }
 
@@ -191,6 +348,7 @@ impl Task {
     /// ```
     pub fn cycle_priority(&mut self) {
         self.priority = self.priority.next();
+        self.priority_level = None;
         self.updated_at = current_timestamp();
     }
 
@@ -227,12 +385,291 @@ impl Task {
         }
     }
 
+    /// Adds a watcher to the task if they aren't already watching.
+    ///
+    /// Surrounding whitespace is trimmed and empty names are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Task;
+    ///
+    /// let mut task = Task::new(1, "Task");
+    /// task.add_watcher("alice");
+    /// task.add_watcher("alice"); // Duplicate, won't be added
+    /// task.add_watcher("  ");    // Empty, ignored
+    ///
+    /// assert_eq!(task.watchers, vec!["alice"]);
+    /// ```
+    pub fn add_watcher(&mut self, watcher: impl Into<String>) {
+        let watcher = watcher.into().trim().to_string();
+        if !watcher.is_empty() && !self.watchers.contains(&watcher) {
+            self.watchers.push(watcher);
+            self.updated_at = current_timestamp();
+        }
+    }
+
+    /// Removes a watcher from the task
+    pub fn remove_watcher(&mut self, watcher: &str) {
+        if let Some(pos) = self.watchers.iter().position(|w| w == watcher.trim()) {
+            self.watchers.remove(pos);
+            self.updated_at = current_timestamp();
+        }
+    }
+
+    /// Checks whether `input` is a due date in either supported format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Task;
+    ///
+    /// assert!(Task::is_valid_due_date("2024-05-01"));
+    /// assert!(Task::is_valid_due_date("2024-05-01 15:30"));
+    /// assert!(!Task::is_valid_due_date("tomorrow"));
+    /// ```
+    pub fn is_valid_due_date(input: &str) -> bool {
+        let input = input.trim();
+        NaiveDateTime::parse_from_str(input, DUE_DATETIME_FORMAT).is_ok()
+            || NaiveDate::parse_from_str(input, DUE_DATE_FORMAT).is_ok()
+    }
+
+    /// Checks whether `input` is a due date more than `max_days` days before today,
+    /// which usually means a typo'd year rather than a real deadline.
+    ///
+    /// Unparseable input is never stale.
+    pub fn is_stale_due_date(input: &str, max_days: i64) -> bool {
+        let input = input.trim();
+        let due_day = NaiveDateTime::parse_from_str(input, DUE_DATETIME_FORMAT)
+            .map(|due| due.date())
+            .or_else(|_| NaiveDate::parse_from_str(input, DUE_DATE_FORMAT));
+        due_day.is_ok_and(|day| (clock::now().date() - day).num_days() > max_days)
+    }
+
     /// Sets the due date for the task
     pub fn set_due_date(&mut self, due_date: Option<String>) {
         self.due_date = due_date;
         self.updated_at = current_timestamp();
     }
 
+    /// Sets the date work on the task can begin
+    pub fn set_start_date(&mut self, start_date: Option<String>) {
+        self.start_date = start_date;
+        self.updated_at = current_timestamp();
+    }
+
+    /// Scores how well the task's title or tags fuzzily match the query.
+    ///
+    /// Query characters must appear in order but not necessarily together, so
+    /// "flgn" matches "Fix login". Returns `None` if neither matches; higher
+    /// scores are better.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Task;
+    ///
+    /// let task = Task::new(1, "Fix login");
+    /// assert!(task.fuzzy_score("flgn").is_some());
+    /// assert!(task.fuzzy_score("signup").is_none());
+    /// ```
+    pub fn fuzzy_score(&self, query: &str) -> Option<i64> {
+        std::iter::once(&self.title)
+            .chain(&self.tags)
+            .filter_map(|text| fuzzy::fuzzy_score(query, text))
+            .max()
+    }
+
+    /// Checks whether the title, description, or any tag contains the query,
+    /// ignoring case. An empty query matches every task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kanban_tui::Task;
+    ///
+    /// let mut task = Task::with_description(1, "Fix login", "Crash on submit");
+    /// task.add_tag("backend");
+    ///
+    /// assert!(task.matches("LOGIN"));
+    /// assert!(task.matches("crash"));
+    /// assert!(task.matches("back"));
+    /// assert!(!task.matches("signup"));
+    /// ```
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+
+        self.title.to_lowercase().contains(&query)
+            || self
+                .description
+                .as_ref()
+                .is_some_and(|d| d.to_lowercase().contains(&query))
+            || self.tags.iter().any(|t| t.to_lowercase().contains(&query))
+    }
+
+    /// Checks whether the task's due date has passed, according to the current clock.
+    ///
+    /// A task due on a date (`YYYY-MM-DD`) is overdue from the day after; one due
+    /// at a time (`YYYY-MM-DD HH:MM`) is overdue from that minute on. Tasks
+    /// without a due date, or with one in neither format, are never overdue.
+    pub fn is_overdue(&self) -> bool {
+        self.is_overdue_at(clock::now())
+    }
+
+    /// Checks whether the task's due date has passed at the given point in time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use kanban_tui::Task;
+    ///
+    /// let mut task = Task::new(1, "Ship release");
+    /// task.set_due_date(Some("2024-05-01".to_string()));
+    ///
+    /// let due_day = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(23, 0, 0).unwrap();
+    /// let next_day = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// assert!(!task.is_overdue_at(due_day));
+    /// assert!(task.is_overdue_at(next_day));
+    ///
+    /// task.set_due_date(Some("2024-05-01 15:00".to_string()));
+    /// let afternoon = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(15, 1, 0).unwrap();
+    /// assert!(task.is_overdue_at(afternoon));
+    /// ```
+    pub fn is_overdue_at(&self, now: NaiveDateTime) -> bool {
+        let Some(due) = self.due_date.as_deref().map(str::trim) else {
+            return false;
+        };
+
+        if let Ok(deadline) = NaiveDateTime::parse_from_str(due, DUE_DATETIME_FORMAT) {
+            return deadline < now;
+        }
+        NaiveDate::parse_from_str(due, DUE_DATE_FORMAT).is_ok_and(|date| date < now.date())
+    }
+
+    /// Checks whether work on the task can begin, according to the current clock.
+    ///
+    /// A task starting on a date (`YYYY-MM-DD`) is started from the beginning of
+    /// that day; one starting at a time (`YYYY-MM-DD HH:MM`) from that minute on.
+    /// Tasks without a start date, or with one in neither format, are always started.
+    pub fn is_started(&self) -> bool {
+        self.is_started_at(clock::now())
+    }
+
+    /// Checks whether work on the task can begin at the given point in time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use kanban_tui::Task;
+    ///
+    /// let mut task = Task::new(1, "Plan sprint");
+    /// task.set_start_date(Some("2024-05-01".to_string()));
+    ///
+    /// let day_before = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap().and_hms_opt(23, 0, 0).unwrap();
+    /// let start_day = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert!(!task.is_started_at(day_before));
+    /// assert!(task.is_started_at(start_day));
+    /// ```
+    pub fn is_started_at(&self, now: NaiveDateTime) -> bool {
+        let Some(start) = self.start_date.as_deref().map(str::trim) else {
+            return true;
+        };
+
+        if let Ok(start) = NaiveDateTime::parse_from_str(start, DUE_DATETIME_FORMAT) {
+            return start <= now;
+        }
+        NaiveDate::parse_from_str(start, DUE_DATE_FORMAT).map_or(true, |date| date <= now.date())
+    }
+
+    /// When the task is due: its due time, or the end of its due day.
+    ///
+    /// Returns `None` without a due date in a supported format.
+    pub(crate) fn due_time(&self) -> Option<NaiveDateTime> {
+        let due = self.due_date.as_deref()?.trim();
+        NaiveDateTime::parse_from_str(due, DUE_DATETIME_FORMAT)
+            .ok()
+            .or_else(|| NaiveDate::parse_from_str(due, DUE_DATE_FORMAT).ok()?.and_hms_opt(23, 59, 59))
+    }
+
+    /// Records that the task entered a column at the current time
+    pub fn enter_column(&mut self, column_name: impl Into<String>) {
+        self.column_history.push((column_name.into(), current_timestamp()));
+    }
+
+    /// How long the task has been in its current column, according to the current clock.
+    ///
+    /// Returns `None` for tasks without column history (e.g. created before it
+    /// was recorded).
+    pub fn time_in_current_column(&self) -> Option<Duration> {
+        self.time_in_current_column_at(clock::now())
+    }
+
+    /// How long the task has been in its current column at the given point in time
+    pub fn time_in_current_column_at(&self, now: NaiveDateTime) -> Option<Duration> {
+        let (_, entered) = self.column_history.last()?;
+        let entered = NaiveDateTime::parse_from_str(entered, TIMESTAMP_FORMAT).ok()?;
+        Some(now - entered)
+    }
+
+    /// How long ago the task was created, according to the current clock.
+    ///
+    /// Returns `None` if `created_at` can't be parsed.
+    pub fn age(&self) -> Option<Duration> {
+        self.age_at(clock::now())
+    }
+
+    /// How long ago the task was created at the given point in time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, NaiveDate};
+    /// use kanban_tui::Task;
+    ///
+    /// let mut task = Task::new(1, "Old task");
+    /// task.created_at = "2024-05-01 09:00:00".to_string();
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2024, 5, 3).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// assert_eq!(task.age_at(now), Some(Duration::days(2)));
+    ///
+    /// task.created_at = "sometime".to_string();
+    /// assert_eq!(task.age_at(now), None);
+    /// ```
+    pub fn age_at(&self, now: NaiveDateTime) -> Option<Duration> {
+        Some(now - self.created_time()?)
+    }
+
+    /// How long ago the task was last changed, according to the current clock.
+    ///
+    /// Returns `None` if `updated_at` isn't a timestamp in the usual format.
+    pub fn since_update(&self) -> Option<Duration> {
+        let updated = NaiveDateTime::parse_from_str(&self.updated_at, TIMESTAMP_FORMAT).ok()?;
+        Some(clock::now() - updated)
+    }
+
+    /// Parsed creation time, if `created_at` is well-formed
+    pub(crate) fn created_time(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(&self.created_at, TIMESTAMP_FORMAT).ok()
+    }
+
+    /// Stamps the task as archived at the current time
+    pub fn mark_archived(&mut self) {
+        self.archived_at = Some(current_timestamp());
+    }
+
+    /// How long ago the task was archived at the given point in time
+    ///
+    /// Returns `None` if the task isn't archived or `archived_at` can't be parsed.
+    pub fn archived_age_at(&self, now: NaiveDateTime) -> Option<Duration> {
+        let archived = NaiveDateTime::parse_from_str(self.archived_at.as_deref()?, TIMESTAMP_FORMAT).ok()?;
+        Some(now - archived)
+    }
+
     /// Updates the title and timestamp
     pub fn update_title(&mut self, title: impl Into<String>) {
         self.title = title.into();
@@ -240,6 +677,63 @@ impl Task {
     }
 }
 
+/// Builder for a [`Task`], created with [`Task::builder`]
+#[derive(Debug, Clone)]
+pub struct TaskBuilder {
+    id: usize,
+    title: String,
+    description: Option<String>,
+    priority: Priority,
+    tags: Vec<String>,
+    due_date: Option<String>,
+    start_date: Option<String>,
+}
+
+impl TaskBuilder {
+    /// Sets the description; an empty description means none
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        let description = description.into();
+        self.description = (!description.is_empty()).then_some(description);
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Adds a tag; empty and duplicate tags are ignored as with [`Task::add_tag`]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !tag.is_empty() && !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    pub fn due_date(mut self, due_date: impl Into<String>) -> Self {
+        self.due_date = Some(due_date.into());
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl Into<String>) -> Self {
+        self.start_date = Some(start_date.into());
+        self
+    }
+
+    /// Creates the task, stamping it with the current time once
+    pub fn build(self) -> Task {
+        Task {
+            description: self.description,
+            priority: self.priority,
+            tags: self.tags,
+            due_date: self.due_date,
+            start_date: self.start_date,
+            ..Task::new(self.id, self.title)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +751,349 @@ mod tests {
         let task = Task::with_description(1, "Test task", "Description");
         assert_eq!(task.description, Some("Description".to_string()));
     }
+
+    #[test]
+    fn test_priority_from_str() {
+        let accepted = [
+            ("high", Priority::High),
+            ("HIGH", Priority::High),
+            ("!!", Priority::High),
+            ("medium", Priority::Medium),
+            ("Med", Priority::Medium),
+            ("!", Priority::Medium),
+            (" low ", Priority::Low),
+            ("·", Priority::Low),
+            ("None", Priority::None),
+        ];
+        for (input, expected) in accepted {
+            assert_eq!(input.parse::<Priority>(), Ok(expected), "{:?}", input);
+        }
+
+        // Every displayed name parses back
+        for priority in Priority::all() {
+            assert_eq!(priority.to_string().parse::<Priority>(), Ok(priority));
+        }
+    }
+
+    #[test]
+    fn test_priority_from_str_rejects_unknown() {
+        let err = "urgent".parse::<Priority>().unwrap_err();
+        assert!(err.contains("urgent"));
+        assert!("".parse::<Priority>().is_err());
+        assert!("!!!".parse::<Priority>().is_err());
+    }
+
+    #[test]
+    fn test_priority_all() {
+        let all = Priority::all();
+        assert_eq!(all.len(), 4);
+        assert_eq!(
+            all,
+            [Priority::High, Priority::Medium, Priority::Low, Priority::None]
+        );
+
+        // Adding a variant breaks this match, forcing `all()` to be updated
+        for priority in all {
+            match priority {
+                Priority::High | Priority::Medium | Priority::Low | Priority::None => {}
+            }
+        }
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    fn frozen_at(year: i32, month: u32, day: u32, hour: u32) -> clock::ClockGuard {
+        let now = NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap();
+        clock::set_thread_clock(clock::FixedClock(now))
+    }
+
+    #[test]
+    fn test_timestamps_use_clock() {
+        let _clock = frozen_at(2024, 3, 15, 9);
+        let task = Task::new(1, "Task");
+        assert_eq!(task.created_at, "2024-03-15 09:00:00");
+        assert_eq!(task.updated_at, "2024-03-15 09:00:00");
+    }
+
+    #[test]
+    fn test_is_overdue_with_frozen_clock() {
+        let mut task = Task::new(1, "Task");
+        task.set_due_date(Some("2024-03-15".to_string()));
+
+        {
+            let _clock = frozen_at(2024, 3, 14, 12);
+            assert!(!task.is_overdue());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 15, 23);
+            assert!(!task.is_overdue());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 16, 0);
+            assert!(task.is_overdue());
+        }
+    }
+
+    #[test]
+    fn test_since_update_uses_clock() {
+        let mut task = {
+            let _clock = frozen_at(2024, 3, 15, 9);
+            Task::new(1, "Task")
+        };
+        let _clock = frozen_at(2024, 3, 15, 11);
+        assert_eq!(task.since_update(), Some(Duration::hours(2)));
+
+        task.updated_at = "yesterday".to_string();
+        assert_eq!(task.since_update(), None);
+    }
+
+    #[test]
+    fn test_is_started_with_frozen_clock() {
+        let mut task = Task::new(1, "Task");
+        {
+            let _clock = frozen_at(2024, 3, 14, 12);
+            assert!(task.is_started());
+        }
+
+        task.set_start_date(Some("2024-03-15".to_string()));
+        {
+            let _clock = frozen_at(2024, 3, 14, 23);
+            assert!(!task.is_started());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 15, 0);
+            assert!(task.is_started());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 20, 9);
+            assert!(task.is_started());
+        }
+    }
+
+    #[test]
+    fn test_start_time_is_started_at_exact_time() {
+        let mut task = Task::new(1, "Task");
+        task.set_start_date(Some("2024-03-15 15:00".to_string()));
+
+        {
+            let _clock = frozen_at(2024, 3, 15, 14);
+            assert!(!task.is_started());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 15, 15);
+            assert!(task.is_started());
+        }
+
+        task.set_start_date(Some("someday".to_string()));
+        let _clock = frozen_at(2000, 1, 1, 0);
+        assert!(task.is_started());
+    }
+
+    #[test]
+    fn test_due_time_is_overdue_at_exact_time() {
+        let mut task = Task::new(1, "Task");
+        task.set_due_date(Some("2024-03-15 15:00".to_string()));
+
+        {
+            let _clock = frozen_at(2024, 3, 15, 14);
+            assert!(!task.is_overdue());
+        }
+        {
+            let _clock = frozen_at(2024, 3, 15, 15);
+            assert!(!task.is_overdue());
+        }
+        {
+            let now = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(15, 0, 1).unwrap();
+            let _clock = clock::set_thread_clock(clock::FixedClock(now));
+            assert!(task.is_overdue());
+        }
+    }
+
+    #[test]
+    fn test_is_stale_due_date() {
+        let _clock = frozen_at(2025, 6, 15, 9);
+
+        assert!(Task::is_stale_due_date("2023-06-15", 365));
+        assert!(Task::is_stale_due_date("2024-06-14 18:00", 365));
+        assert!(!Task::is_stale_due_date("2024-06-15", 365));
+        assert!(!Task::is_stale_due_date("2025-06-01", 365));
+        assert!(!Task::is_stale_due_date("2026-01-01", 365));
+        assert!(Task::is_stale_due_date("2025-06-01", 7));
+        assert!(!Task::is_stale_due_date("tomorrow", 365));
+    }
+
+    #[test]
+    fn test_is_valid_due_date() {
+        assert!(Task::is_valid_due_date("2024-03-15"));
+        assert!(Task::is_valid_due_date(" 2024-03-15 09:30 "));
+        assert!(!Task::is_valid_due_date("2024-03-15 25:00"));
+        assert!(!Task::is_valid_due_date("2024-13-01"));
+        assert!(!Task::is_valid_due_date(""));
+    }
+
+    #[test]
+    fn test_is_overdue_without_valid_due_date() {
+        let _clock = frozen_at(2030, 1, 1, 12);
+        let mut task = Task::new(1, "Task");
+        assert!(!task.is_overdue());
+
+        task.set_due_date(Some("next tuesday".to_string()));
+        assert!(!task.is_overdue());
+    }
+
+    #[test]
+    fn test_matches_title_description_and_tags() {
+        let mut task = Task::with_description(1, "Write Docs", "Cover the storage API");
+        task.add_tag("documentation");
+
+        assert!(task.matches("docs"));
+        assert!(task.matches("STORAGE"));
+        assert!(task.matches("document"));
+        assert!(task.matches("  "));
+        assert!(!task.matches("tests"));
+    }
+
+    #[test]
+    fn test_priority_for_level() {
+        // The default four-level scheme maps one-to-one
+        for (idx, priority) in Priority::all().into_iter().enumerate() {
+            assert_eq!(Priority::for_level(idx, 4), priority);
+            assert_eq!(priority.index(), idx);
+        }
+
+        // A five-level P0-P4 scheme spreads across the built-in levels
+        assert_eq!(Priority::for_level(0, 5), Priority::High);
+        assert_eq!(Priority::for_level(2, 5), Priority::Medium);
+        assert_eq!(Priority::for_level(4, 5), Priority::None);
+        assert_eq!(Priority::for_level(9, 5), Priority::None);
+        assert_eq!(Priority::for_level(0, 0), Priority::None);
+    }
+
+    #[test]
+    fn test_priority_to_level_inverts_for_level() {
+        for level_count in 1..=8 {
+            for priority in Priority::all() {
+                let level = priority.to_level(level_count);
+                assert!(level < level_count);
+                if level_count >= 4 {
+                    assert_eq!(Priority::for_level(level, level_count), priority);
+                }
+            }
+            assert_eq!(Priority::None.to_level(level_count), level_count - 1);
+        }
+        assert_eq!(Priority::Low.to_level(5), 3);
+        assert_eq!(Priority::None.to_level(0), 0);
+    }
+
+    #[test]
+    fn test_add_watcher_dedupes_and_ignores_empty() {
+        let mut task = Task::new(1, "Task");
+        task.add_watcher("alice");
+        task.add_watcher(" alice ");
+        task.add_watcher("");
+        task.add_watcher("   ");
+        task.add_watcher("bob");
+        assert_eq!(task.watchers, vec!["alice", "bob"]);
+
+        task.remove_watcher("alice");
+        task.remove_watcher("nobody");
+        assert_eq!(task.watchers, vec!["bob"]);
+    }
+
+    #[test]
+    fn test_watchers_serde_round_trip() {
+        let mut task = Task::new(1, "Task");
+        task.add_watcher("alice");
+        let json = serde_json::to_string(&task).unwrap();
+        let loaded: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, task);
+
+        // Tasks saved before watchers existed still load
+        let legacy: Task = serde_json::from_str(r#"{"id": 2, "title": "Old", "description": null}"#).unwrap();
+        assert!(legacy.watchers.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_fields_survive_round_trip() {
+        let json = r#"{"id": 1, "title": "From the future", "description": null,
+            "assignee": "alice", "checklist": [{"text": "Step", "done": true}]}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.extra["assignee"], "alice");
+        assert_eq!(task.extra.len(), 2);
+
+        let saved: serde_json::Value = serde_json::to_value(&task).unwrap();
+        assert_eq!(saved["assignee"], "alice");
+        assert_eq!(saved["checklist"][0]["done"], true);
+        assert_eq!(serde_json::from_value::<Task>(saved).unwrap(), task);
+
+        // Known fields are never duplicated into the extras
+        let plain = serde_json::to_value(Task::new(2, "Today")).unwrap();
+        let reloaded: Task = serde_json::from_value(plain).unwrap();
+        assert!(reloaded.extra.is_empty());
+    }
+
+    #[test]
+    fn test_time_in_current_column() {
+        let mut task = Task::new(1, "Task");
+        assert!(task.time_in_current_column().is_none());
+
+        {
+            let _clock = frozen_at(2024, 3, 15, 9);
+            task.enter_column("In Progress");
+        }
+        let _clock = frozen_at(2024, 3, 18, 12);
+        assert_eq!(task.time_in_current_column(), Some(Duration::hours(75)));
+    }
+
+    #[test]
+    fn test_builder_sets_every_field() {
+        let _clock = frozen_at(2024, 3, 15, 9);
+        let task = Task::builder(7, "Ship release")
+            .description("Tag and publish")
+            .priority(Priority::Medium)
+            .tag("release")
+            .tag("ops")
+            .tag("release")
+            .tag("")
+            .due_date("2024-03-20 17:00")
+            .build();
+
+        assert_eq!(task.id, 7);
+        assert_eq!(task.title, "Ship release");
+        assert_eq!(task.description.as_deref(), Some("Tag and publish"));
+        assert_eq!(task.priority, Priority::Medium);
+        assert_eq!(task.tags, vec!["release", "ops"]);
+        assert_eq!(task.due_date.as_deref(), Some("2024-03-20 17:00"));
+        assert_eq!(task.created_at, "2024-03-15 09:00:00");
+        assert_eq!(task.updated_at, task.created_at);
+        assert!(task.watchers.is_empty());
+        assert!(task.column_history.is_empty());
+    }
+
+    #[test]
+    fn test_builder_defaults_match_new() {
+        let _clock = frozen_at(2024, 3, 15, 9);
+        assert_eq!(Task::builder(1, "Task").description("").build(), Task::new(1, "Task"));
+    }
+
+    #[test]
+    fn test_age_with_frozen_clock() {
+        let task = {
+            let _clock = frozen_at(2024, 3, 15, 9);
+            Task::new(1, "Task")
+        };
+
+        let _clock = frozen_at(2024, 3, 18, 12);
+        assert_eq!(task.age(), Some(Duration::hours(75)));
+    }
+
+    #[test]
+    fn test_age_of_unparseable_timestamp() {
+        let _clock = frozen_at(2024, 3, 18, 12);
+        let mut task = Task::new(1, "Task");
+        task.created_at = "yesterday".to_string();
+        assert_eq!(task.age(), None);
+    }
 }
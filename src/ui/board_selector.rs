@@ -1,5 +1,6 @@
 //! Board selector popup rendering for the Kanban TUI.
 
+use super::{centered_popup, theme};
 use crate::app::App;
 use ratatui::{
     layout::Rect,
@@ -11,21 +12,14 @@ use ratatui::{
 
 pub fn render_board_selector(f: &mut Frame, app: &App, area: Rect) {
     // Create centered popup area
-    let popup_width = 50.min(area.width - 4);
-    let popup_height = (app.available_boards.len() as u16 + 6).min(area.height - 4);
-    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
-
-    let popup_area = Rect {
-        x: area.x + popup_x,
-        y: area.y + popup_y,
-        width: popup_width,
-        height: popup_height,
-    };
+    let boards = app.selector_boards();
+    let board_rows = u16::try_from(boards.len()).unwrap_or(u16::MAX);
+    let popup_area = centered_popup(area, 50, board_rows.saturating_add(8));
+    let popup_width = popup_area.width;
+    let popup_height = popup_area.height;
 
     // Build board list items
-    let items: Vec<ListItem> = app
-        .available_boards
+    let items: Vec<ListItem> = boards
         .iter()
         .enumerate()
         .map(|(idx, board_name)| {
@@ -33,13 +27,15 @@ pub fn render_board_selector(f: &mut Frame, app: &App, area: Rect) {
             let is_current = board_name == &app.current_board_name;
 
             let prefix = if is_current { "✓ " } else { "  " };
-            let content = format!("{}{}", prefix, board_name);
+            // Without colors the selection highlight is invisible, so mark it in the text
+            let content = if is_selected && app.config.no_color {
+                format!("{}*{}*", prefix, board_name)
+            } else {
+                format!("{}{}", prefix, board_name)
+            };
 
             let style = if is_selected {
-                Style::default()
-                    .bg(Color::Cyan)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD)
+                theme::selection_style(&app.config)
             } else if is_current {
                 Style::default()
                     .fg(Color::Green)
@@ -55,15 +51,16 @@ pub fn render_board_selector(f: &mut Frame, app: &App, area: Rect) {
     // Clear the area and render popup
     f.render_widget(Clear, popup_area);
 
+    let title = if app.show_archived { " Archived Boards " } else { " Select Board " };
     let list = List::new(items).block(
         Block::default()
-            .title(" Select Board ")
+            .title(title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan)),
     );
 
     // Split area for list and help text
-    let list_height = popup_height.saturating_sub(4);
+    let list_height = popup_height.saturating_sub(6);
     let list_area = Rect {
         x: popup_area.x,
         y: popup_area.y,
@@ -75,12 +72,12 @@ pub fn render_board_selector(f: &mut Frame, app: &App, area: Rect) {
         x: popup_area.x,
         y: popup_area.y + list_height,
         width: popup_width,
-        height: 4,
+        height: popup_height - list_height,
     };
 
     f.render_widget(list, list_area);
 
-    // Render help text at bottom
+    // Render help text at bottom, one line per group so none is clipped by the popup width
     let help_text = vec![
         Line::from(""),
         Line::from(vec![
@@ -89,7 +86,15 @@ pub fn render_board_selector(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("n/B", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(": new | "),
             Span::styled("d", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": delete | "),
+            Span::raw(": delete"),
+        ]),
+        Line::from(vec![
+            Span::styled("a/A", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(": archive/show archived"),
+        ]),
+        Line::from(vec![
+            Span::styled("c", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(": copy columns here | "),
             Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(": cancel"),
         ]),
@@ -1,20 +1,21 @@
 //! Column rendering for the Kanban TUI.
 
-use kanban_tui::{Column, Priority};
+use super::theme;
+use crate::app::App;
+use crate::config::{CardStyle, Config, NotStartedStyle};
+use kanban_tui::{Column, SortMode, Task};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
+use std::ops::Range;
+
+pub fn render_column(f: &mut Frame, app: &App, column_index: usize, area: Rect) {
+    let is_selected_column = column_index == app.selected_column;
 
-pub fn render_column(
-    f: &mut Frame,
-    column: &Column,
-    is_selected_column: bool,
-    selected_task_index: Option<usize>,
-    area: Rect,
-) {
     let color = if is_selected_column {
         Color::Cyan
     } else {
@@ -30,135 +31,697 @@ pub fn render_column(
     };
 
     let title = if is_selected_column {
-        format!("▶ {} ({}) ◀", column.name, column.tasks.len())
+        format!("▶ {} ◀", column_header(app, column_index))
     } else {
-        format!("{} ({})", column.name, column.tasks.len())
+        column_header(app, column_index)
+    };
+
+    // Columns over their WIP limit get a red header, or a marker without colors
+    let over_wip = app.board.over_wip_columns().contains(&column_index);
+    let title = if over_wip && app.config.no_color {
+        format!("{} [OVER WIP]", title)
+    } else {
+        title
+    };
+    let title_style = if over_wip {
+        border_style.fg(Color::Red)
+    } else {
+        border_style
     };
 
     let block = Block::default()
-        .title(title)
+        .title(Span::styled(title, title_style))
         .borders(Borders::ALL)
         .border_style(border_style);
 
+    let (summary_area, list_area) = split_column_area(block.inner(area), app.config.column_summary);
+    f.render_widget(block, area);
+
+    // The summary sits outside the list, so it stays put while the cards scroll
+    if let Some(summary_area) = summary_area {
+        let summary = Paragraph::new(column_summary(app, column_index))
+            .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(summary, summary_area);
+    }
+
     // Create list items from tasks with numbering and selection highlighting
     // Calculate card width based on available area (accounting for borders and padding)
     let card_width = (area.width.saturating_sub(4)).max(20) as usize;
+    let viewport_height = list_area.height as usize;
 
-    let items: Vec<ListItem> = column
-        .tasks
+    let items: Vec<ListItem> = column_cards(app, column_index, viewport_height, card_width)
+        .into_iter()
+        .map(ListItem::new)
+        .collect();
+
+    f.render_widget(List::new(items), list_area);
+}
+
+/// Split a column's inner area into the optional one-line summary and the card list
+fn split_column_area(inner: Rect, with_summary: bool) -> (Option<Rect>, Rect) {
+    if !with_summary {
+        return (None, inner);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+    (Some(chunks[0]), chunks[1])
+}
+
+/// One-line summary of a column, e.g. "3 tasks · WIP 3/5 · sort: priority"
+fn column_summary(app: &App, column_index: usize) -> String {
+    let (count, limit) = app.board.column_load(column_index);
+    let mut parts = vec![format!("{} task{}", count, if count == 1 { "" } else { "s" })];
+    if let Some(limit) = limit {
+        parts.push(format!("WIP {}/{}", count, limit));
+    }
+    let sort_mode = app.board.columns[column_index].sort_mode;
+    if sort_mode != SortMode::Manual {
+        parts.push(format!("sort: {}", sort_mode));
+    }
+    parts.join(" · ")
+}
+
+/// Build the cards for the tasks of a column that fit in the viewport.
+///
+/// Cards are numbered 1..n among the tasks that pass the active filter, so
+/// the numbering stays contiguous when some tasks are hidden.
+fn column_cards(
+    app: &App,
+    column_index: usize,
+    viewport_height: usize,
+    card_width: usize,
+) -> Vec<Text<'static>> {
+    let column = &app.board.columns[column_index];
+    let selected_task_index = if column_index == app.selected_column {
+        app.selected_task_index
+    } else {
+        None
+    };
+    let is_done_column = app.board.is_done_column(column_index);
+
+    // Tasks hidden by the active filter are skipped entirely
+    let visible_tasks: Vec<(usize, &Task)> = app
+        .visible_task_indices(column_index)
+        .into_iter()
+        .map(|idx| (idx, &column.tasks[idx]))
+        .collect();
+    let selected_position = selected_task_index
+        .and_then(|selected| visible_tasks.iter().position(|(idx, _)| *idx == selected));
+
+    // Custom priority schemes show their level name instead of the symbol
+    let priority_label = |task: &Task| {
+        app.board
+            .has_custom_priority_scheme()
+            .then(|| app.board.priority_label(task))
+    };
+
+    // Only build cards for the window of tasks that fits in the viewport
+    let heights: Vec<usize> = visible_tasks
         .iter()
         .enumerate()
-        .map(|(idx, task)| {
-            use ratatui::text::{Line, Span};
+        .map(|(position, &(idx, task))| {
+            let title = title_line(
+                position + 1,
+                task,
+                selected_task_index == Some(idx),
+                priority_label(task),
+                &app.config,
+            );
+            let title_rows = title_rows(&title, &app.config, card_width).len();
+            card_height(task, &app.config, title_rows)
+        })
+        .collect();
+    let visible = visible_range(&heights, selected_position, viewport_height);
+    let first_number = visible.start + 1;
 
-            let is_selected_task = selected_task_index == Some(idx);
+    visible_tasks[visible]
+        .iter()
+        .enumerate()
+        .map(|(offset, &(idx, task))| {
+            build_card(
+                first_number + offset,
+                task,
+                selected_task_index == Some(idx),
+                is_done_column,
+                priority_label(task),
+                &app.config,
+                card_width,
+            )
+        })
+        .collect()
+}
 
-            // Determine color based on priority
-            let priority_color = match task.priority {
-                Priority::High => Color::Red,
-                Priority::Medium => Color::Yellow,
-                Priority::Low => Color::Green,
-                Priority::None => Color::White,
-            };
+/// Width of a collapsed column: its header title plus borders
+pub fn collapsed_column_width(column: &Column) -> u16 {
+    let title_width = collapsed_title(column).chars().count() + 2;
+    u16::try_from(title_width).unwrap_or(u16::MAX)
+}
 
-            // Base style for the card
-            let base_style = if is_selected_task {
-                Style::default()
-                    .bg(Color::Cyan)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(priority_color)
-            };
+fn collapsed_title(column: &Column) -> String {
+    load_label(&column.name, column.tasks.len(), column.wip_limit)
+}
+
+/// Column name with its task count, and WIP limit when set (e.g. "In Progress (3/5)")
+fn column_header(app: &App, column_index: usize) -> String {
+    let (count, limit) = app.board.column_load(column_index);
+    load_label(&app.board.columns[column_index].name, count, limit)
+}
+
+fn load_label(name: &str, count: usize, limit: Option<usize>) -> String {
+    match limit {
+        Some(limit) => format!("{} ({}/{})", name, count, limit),
+        None => format!("{} ({})", name, count),
+    }
+}
+
+/// Render a collapsed column as just its name and task count
+pub fn render_collapsed_column(f: &mut Frame, app: &App, column_index: usize, area: Rect) {
+    let column = &app.board.columns[column_index];
+    let block = Block::default()
+        .title(collapsed_title(column))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    f.render_widget(block, area);
+}
 
-            let border_style = if is_selected_task {
-                Style::default()
-                    .bg(Color::Cyan)
-                    .fg(Color::Black)
+/// Build the card for a single task, labelled with its display number
+fn build_card(
+    number: usize,
+    task: &Task,
+    is_selected_task: bool,
+    is_done_column: bool,
+    priority_label: Option<String>,
+    config: &Config,
+    card_width: usize,
+) -> Text<'static> {
+    // Determine color based on priority; tasks that can't be started yet are muted
+    let is_waiting = !task.is_started();
+    let priority_color = if is_waiting && config.not_started == NotStartedStyle::Dim {
+        Color::DarkGray
+    } else {
+        theme::priority_color(task.priority)
+    };
+
+    // Base style for the card; borders and metadata of a selected card share
+    // its highlight without the bold, and metadata stays dimmed
+    let selection_style = theme::selection_style(config);
+    let base_style = if is_selected_task {
+        selection_style
+    } else {
+        Style::default().fg(priority_color)
+    };
+
+    let border_style = if is_selected_task {
+        selection_style.remove_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(priority_color)
+    };
+
+    let meta_style = if is_selected_task {
+        selection_style.remove_modifier(Modifier::BOLD).fg(Color::DarkGray)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    // Build card content lines as styled spans (padded to the card width below)
+    let mut content_lines: Vec<(Vec<Span>, Style)> = Vec::new();
+
+    // Line 1: Number, priority symbol, and title (over several lines when wrapped)
+    let title = title_line(number, task, is_selected_task, priority_label, config);
+    for row in title_rows(&title, config, card_width) {
+        content_lines.push((vec![Span::styled(row, base_style)], base_style));
+    }
+
+    // Line 2: Tags (if present), each in its configured color
+    if !task.tags.is_empty() {
+        let mut spans = vec![Span::styled("  ", meta_style)];
+        for (tag_idx, tag) in task.tags.iter().enumerate() {
+            if tag_idx > 0 {
+                spans.push(Span::styled(", ", meta_style));
+            }
+            let tag_style = if is_selected_task {
+                meta_style
             } else {
-                Style::default().fg(priority_color)
+                Style::default().fg(theme::tag_color(config, tag))
             };
+            spans.push(Span::styled(tag.clone(), tag_style));
+        }
+        content_lines.push((spans, meta_style));
+    }
 
-            let meta_style = if is_selected_task {
-                Style::default()
-                    .bg(Color::Cyan)
-                    .fg(Color::DarkGray)
+    // Line 3: Due date (if present), flagged when overdue outside the done column
+    if let Some(due) = &task.due_date {
+        let due_line = if !is_done_column && task.is_overdue() {
+            let overdue_style = if is_selected_task {
+                meta_style.fg(theme::OVERDUE_COLOR)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme::OVERDUE_COLOR)
             };
+            let marker = if config.no_color { "[OVERDUE]" } else { "(overdue)" };
+            Span::styled(format!("  due: {} {}", due, marker), overdue_style)
+        } else {
+            Span::styled(format!("  due: {}", due), meta_style)
+        };
+        content_lines.push((vec![due_line], meta_style));
+    }
 
-            // Build card content lines (text content only, for padding calculation)
-            let mut content_lines = Vec::new();
+    // Line 4: Start date, while the task is still waiting for it
+    if let Some(start) = task.start_date.as_ref().filter(|_| is_waiting) {
+        let start_line = Span::styled(format!("  starts: {}", start), meta_style);
+        content_lines.push((vec![start_line], meta_style));
+    }
 
-            // Line 1: Number, priority symbol, and title
-            let priority_symbol = task.priority.symbol();
-            let priority_str = if !priority_symbol.is_empty() {
-                format!("{} ", priority_symbol)
-            } else {
-                String::new()
-            };
-            let title_line = format!("{}. {}{}", idx + 1, priority_str, task.title);
-            content_lines.push(title_line);
+    // Line 5: Age (if enabled and the creation time is readable)
+    if let Some(age) = shown_age(task, config) {
+        let age_line = Span::styled(format!("  age: {}", format_age(age)), meta_style);
+        content_lines.push((vec![age_line], meta_style));
+    }
 
-            // Line 2: Tags (if present)
-            if !task.tags.is_empty() {
-                content_lines.push(format!("  {}", task.tags.join(", ")));
-            }
+    let boxed = config.card_style == CardStyle::Boxed;
+    let mut lines = Vec::new();
 
-            // Line 3: Due date (if present)
-            if let Some(due) = &task.due_date {
-                content_lines.push(format!("  due: {}", due));
-            }
+    // Top border: ╭──────╮
+    if boxed {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("╭{}╮", "─".repeat(card_width.saturating_sub(2))),
+                border_style
+            )
+        ]));
+    }
 
-            // Build the bordered card
-            let mut lines = Vec::new();
-
-            // Top border: ╭──────╮
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("╭{}╮", "─".repeat(card_width.saturating_sub(2))),
-                    border_style
-                )
-            ]));
-
-            // Content lines with side borders: │ content │
-            for content in &content_lines {
-                let display_content = if content.len() > card_width.saturating_sub(4) {
-                    // Truncate if too long
-                    format!("{:width$}", &content[..card_width.saturating_sub(7)], width = card_width.saturating_sub(4))
-                } else {
-                    // Pad to fill width
-                    format!("{:width$}", content, width = card_width.saturating_sub(4))
-                };
-
-                let line_style = if content == &content_lines[0] {
-                    base_style // First line uses base style (title)
-                } else {
-                    meta_style // Metadata lines use meta style
-                };
-
-                lines.push(Line::from(vec![
-                    Span::styled("│ ", border_style),
-                    Span::styled(display_content, line_style),
-                    Span::styled(" │", border_style),
-                ]));
-            }
+    // Content lines, with side borders when boxed: │ content │
+    for (spans, line_style) in content_lines {
+        if boxed {
+            let mut line = vec![Span::styled("│ ", border_style)];
+            line.extend(fit_spans(spans, card_width.saturating_sub(4), line_style));
+            line.push(Span::styled(" │", border_style));
+            lines.push(Line::from(line));
+        } else {
+            lines.push(Line::from(fit_spans(spans, card_width, line_style)));
+        }
+    }
 
-            // Bottom border: ╰──────╯
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("╰{}╯", "─".repeat(card_width.saturating_sub(2))),
-                    border_style
-                )
-            ]));
+    // Bottom border: ╰──────╯
+    if boxed {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("╰{}╯", "─".repeat(card_width.saturating_sub(2))),
+                border_style
+            )
+        ]));
+    }
 
-            // Add empty line for spacing between cards
-            lines.push(Line::from(""));
+    // Blank lines for spacing between cards
+    for _ in 0..config.card_spacing {
+        lines.push(Line::from(""));
+    }
 
-            ListItem::new(lines)
-        })
-        .collect();
+    Text::from(lines)
+}
+
+/// A card's title line: number, priority symbol or label, and the task title
+fn title_line(
+    number: usize,
+    task: &Task,
+    is_selected_task: bool,
+    priority_label: Option<String>,
+    config: &Config,
+) -> String {
+    let priority_symbol = if config.no_color {
+        theme::priority_marker(task.priority)
+    } else {
+        task.priority.symbol()
+    };
+    let priority_str = if let Some(label) = priority_label {
+        format!("[{}] ", label)
+    } else if !priority_symbol.is_empty() {
+        format!("{} ", priority_symbol)
+    } else {
+        String::new()
+    };
+    // Without colors the selection highlight is invisible, so mark it in the text
+    if is_selected_task && config.no_color {
+        format!("*{}. {}{}*", number, priority_str, task.title)
+    } else {
+        format!("{}. {}{}", number, priority_str, task.title)
+    }
+}
+
+/// The rows a card's title line is drawn on: just the line itself (truncated
+/// when drawn) unless titles wrap to the card's content width
+fn title_rows(title: &str, config: &Config, card_width: usize) -> Vec<String> {
+    if !config.wrap_titles {
+        return vec![title.to_string()];
+    }
+    let content_width = match config.card_style {
+        CardStyle::Boxed => card_width.saturating_sub(4),
+        CardStyle::Plain => card_width,
+    };
+    wrap_text(title, content_width)
+}
+
+/// Word-wrap `text` into lines of at most `width` characters.
+///
+/// Words longer than a whole line are split across lines; the result always
+/// has at least one (possibly empty) line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if current_len > 0 && current_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        // Words that can't fit on a line of their own are broken up
+        while current_len == 0 && word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.extend(&word);
+        current_len += word.len();
+    }
+    if current_len > 0 || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// The task's age if cards should show it
+fn shown_age(task: &Task, config: &Config) -> Option<chrono::Duration> {
+    config.show_age.then(|| task.age()).flatten()
+}
+
+/// Format an age by its largest unit, e.g. "3d", "5h" or "12m"
+fn format_age(age: chrono::Duration) -> String {
+    if age.num_days() > 0 {
+        format!("{}d", age.num_days())
+    } else if age.num_hours() > 0 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}m", age.num_minutes().max(0))
+    }
+}
+
+/// Number of lines a task's card occupies, including borders and spacer lines,
+/// given the number of rows its title takes up
+fn card_height(task: &Task, config: &Config, title_rows: usize) -> usize {
+    let mut content_lines = title_rows;
+    if !task.tags.is_empty() {
+        content_lines += 1;
+    }
+    if task.due_date.is_some() {
+        content_lines += 1;
+    }
+    if task.start_date.is_some() && !task.is_started() {
+        content_lines += 1;
+    }
+    if shown_age(task, config).is_some() {
+        content_lines += 1;
+    }
+
+    let borders = match config.card_style {
+        CardStyle::Boxed => 2,
+        CardStyle::Plain => 0,
+    };
+    content_lines + borders + usize::from(config.card_spacing)
+}
+
+/// Compute which cards to render so the selected card stays in view.
+///
+/// Scrolls just far enough that the selected card fits in the viewport, then
+/// includes every following card that is at least partially visible.
+fn visible_range(heights: &[usize], selected: Option<usize>, viewport_height: usize) -> Range<usize> {
+    let mut start = 0;
+    if let Some(selected) = selected.filter(|&s| s < heights.len()) {
+        let mut used: usize = heights[..=selected].iter().sum();
+        while used > viewport_height && start < selected {
+            used -= heights[start];
+            start += 1;
+        }
+    }
+
+    let mut end = start;
+    let mut used = 0;
+    while end < heights.len() && used < viewport_height {
+        used += heights[end];
+        end += 1;
+    }
+
+    start..end
+}
+
+/// Truncate or pad a card line's spans to exactly `width` characters.
+///
+/// Overlong lines are cut short of the width to leave a visible gap before the
+/// card border; padding uses `pad_style` so selection highlighting fills the card.
+fn fit_spans(spans: Vec<Span<'_>>, width: usize, pad_style: Style) -> Vec<Span<'_>> {
+    let total: usize = spans.iter().map(|span| span.content.chars().count()).sum();
+    let limit = if total > width { width.saturating_sub(3) } else { width };
+
+    let mut used = 0;
+    let mut fitted = Vec::new();
+    for span in spans {
+        let len = span.content.chars().count();
+        if used + len <= limit {
+            used += len;
+            fitted.push(span);
+        } else {
+            let truncated: String = span.content.chars().take(limit - used).collect();
+            used = limit;
+            fitted.push(Span::styled(truncated, span.style));
+            break;
+        }
+    }
+
+    if used < width {
+        fitted.push(Span::styled(" ".repeat(width - used), pad_style));
+    }
+    fitted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(spans: &[Span]) -> String {
+        spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_card_numbers_are_contiguous_when_filtered() {
+        use crate::config::Config;
+        use kanban_tui::storage::Storage;
+
+        let dir = std::env::temp_dir().join(format!("kanban-column-test-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        for title in ["first match", "hidden", "second match"] {
+            app.board.add_task(0, title).unwrap();
+        }
+        app.filter = kanban_tui::TaskFilter::parse("match");
+
+        let titles: Vec<String> = column_cards(&app, 0, 100, 40)
+            .iter()
+            .map(|card| text(&card.lines[1].spans))
+            .collect();
+        assert_eq!(titles.len(), 2);
+        assert!(titles[0].starts_with("│ 1. first match"), "{}", titles[0]);
+        assert!(titles[1].starts_with("│ 2. second match"), "{}", titles[1]);
+    }
+
+    #[test]
+    fn test_split_column_area_pins_summary() {
+        let inner = Rect::new(1, 1, 30, 20);
+        assert_eq!(split_column_area(inner, false), (None, inner));
+
+        let (summary, list) = split_column_area(inner, true);
+        assert_eq!(summary, Some(Rect::new(1, 1, 30, 1)));
+        assert_eq!(list, Rect::new(1, 2, 30, 19));
+
+        // A column too short for cards still keeps the summary
+        let (summary, list) = split_column_area(Rect::new(0, 0, 30, 1), true);
+        assert_eq!(summary.unwrap().height, 1);
+        assert_eq!(list.height, 0);
+    }
+
+    #[test]
+    fn test_column_summary() {
+        use kanban_tui::storage::Storage;
+
+        let dir = std::env::temp_dir().join(format!("kanban-column-summary-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        app.board.add_task(1, "Task").unwrap();
+        assert_eq!(column_summary(&app, 0), "0 tasks");
+        assert_eq!(column_summary(&app, 1), "1 task");
+
+        app.board.set_wip_limit(1, Some(3)).unwrap();
+        app.board.set_sort_mode(1, SortMode::Priority).unwrap();
+        assert_eq!(column_summary(&app, 1), "1 task · WIP 1/3 · sort: priority");
+    }
+
+    #[test]
+    fn test_load_label() {
+        assert_eq!(load_label("In Progress", 3, Some(5)), "In Progress (3/5)");
+        assert_eq!(load_label("To Do", 3, None), "To Do (3)");
+    }
+
+    #[test]
+    fn test_collapsed_column_width_fits_title() {
+        let mut column = Column::new("Done");
+        assert_eq!(collapsed_column_width(&column), "Done (0)".len() as u16 + 2);
+
+        column.add_task(Task::new(1, "Task"));
+        column.add_task(Task::new(2, "Task"));
+        assert_eq!(collapsed_column_width(&column), "Done (2)".len() as u16 + 2);
+    }
+
+    #[test]
+    fn test_fit_spans_pads_short_lines() {
+        let fitted = fit_spans(vec![Span::raw("ab"), Span::raw("cd")], 6, Style::default());
+        assert_eq!(text(&fitted), "abcd  ");
+    }
+
+    #[test]
+    fn test_fit_spans_truncates_long_lines() {
+        let fitted = fit_spans(vec![Span::raw("abcd"), Span::raw("efgh")], 6, Style::default());
+        assert_eq!(text(&fitted), "abc   ");
+    }
+
+    #[test]
+    fn test_card_height_matches_built_card() {
+        let mut config = Config::default();
+        let mut task = Task::new(1, "Task");
+        assert_eq!(build_card(1, &task, false, false, None, &config, 30).height(), card_height(&task, &config, 1));
+
+        task.add_tag("bug");
+        task.set_due_date(Some("2024-01-01".to_string()));
+        assert_eq!(build_card(1, &task, true, false, None, &config, 30).height(), card_height(&task, &config, 1));
+
+        task.set_start_date(Some("2999-01-01".to_string()));
+        assert_eq!(build_card(1, &task, false, false, None, &config, 30).height(), card_height(&task, &config, 1));
+
+        config.show_age = true;
+        assert_eq!(build_card(1, &task, true, false, None, &config, 30).height(), card_height(&task, &config, 1));
+        task.created_at = "unknown".to_string();
+        assert_eq!(build_card(1, &task, true, false, None, &config, 30).height(), card_height(&task, &config, 1));
+    }
+
+    #[test]
+    fn test_wrap_text_splits_at_width() {
+        let title = "1. Investigate why the nightly export job times out on large boards";
+        let lines = wrap_text(title, 26);
+        assert_eq!(
+            lines,
+            vec!["1. Investigate why the", "nightly export job times", "out on large boards"]
+        );
+        assert!(lines.iter().all(|line| line.chars().count() <= 26));
+
+        assert_eq!(wrap_text("Short", 26), vec!["Short"]);
+        assert_eq!(wrap_text("", 26), vec![""]);
+        // Words longer than a line are broken up
+        assert_eq!(wrap_text("abcdefghij xy", 4), vec!["abcd", "efgh", "ij", "xy"]);
+    }
+
+    #[test]
+    fn test_wrapped_titles_grow_the_card() {
+        let task = Task::new(1, "Investigate why the nightly export job times out on large boards");
+        let config = Config { wrap_titles: true, ..Config::default() };
+
+        // A 30 wide boxed card leaves 26 columns for the title
+        let card = build_card(1, &task, false, false, None, &config, 30);
+        assert_eq!(text(&card.lines[1].spans), "│ 1. Investigate why the     │");
+        assert_eq!(text(&card.lines[3].spans), "│ out on large boards        │");
+        assert_eq!(card.height(), card_height(&task, &config, 3));
+
+        // Truncation stays the default
+        let card = build_card(1, &task, false, false, None, &Config::default(), 30);
+        assert_eq!(card.height(), card_height(&task, &Config::default(), 1));
+    }
+
+    #[test]
+    fn test_card_lines_follow_spacing_and_style() {
+        let mut task = Task::new(1, "Task");
+        task.add_tag("bug");
+
+        for (spacing, style, expected) in [
+            (1, CardStyle::Boxed, 5),
+            (0, CardStyle::Boxed, 4),
+            (3, CardStyle::Boxed, 7),
+            (0, CardStyle::Plain, 2),
+            (1, CardStyle::Plain, 3),
+        ] {
+            let config = Config { card_spacing: spacing, card_style: style, ..Config::default() };
+            let card = build_card(1, &task, false, false, None, &config, 30);
+            assert_eq!(card.height(), expected, "spacing {} {:?}", spacing, style);
+            assert_eq!(card_height(&task, &config, 1), expected);
+        }
+
+        let config = Config { card_style: CardStyle::Plain, ..Config::default() };
+        let card = build_card(1, &task, false, false, None, &config, 30);
+        assert!(text(&card.lines[0].spans).starts_with("1. Task"));
+        assert_eq!(text(&card.lines[0].spans).chars().count(), 30);
+    }
+
+    #[test]
+    fn test_no_color_cards_use_text_markers() {
+        let task = Task::builder(1, "Outage").priority(kanban_tui::Priority::High).build();
+        let plain = Config { card_style: CardStyle::Plain, ..Config::default() };
+        let no_color = Config { no_color: true, ..plain.clone() };
+
+        let card = build_card(1, &task, false, false, None, &plain, 30);
+        assert!(text(&card.lines[0].spans).starts_with("1. !! Outage"));
+
+        let card = build_card(1, &task, false, false, None, &no_color, 30);
+        assert!(text(&card.lines[0].spans).starts_with("1. [HIGH] Outage"));
+
+        let card = build_card(1, &task, true, false, None, &no_color, 30);
+        assert!(text(&card.lines[0].spans).starts_with("*1. [HIGH] Outage*"));
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(chrono::Duration::minutes(12)), "12m");
+        assert_eq!(format_age(chrono::Duration::minutes(300)), "5h");
+        assert_eq!(format_age(chrono::Duration::hours(80)), "3d");
+        assert_eq!(format_age(chrono::Duration::minutes(-5)), "0m");
+    }
+
+    #[test]
+    fn test_visible_range_bounded_by_viewport() {
+        let heights = vec![4; 500];
+
+        for selected in [None, Some(0), Some(250), Some(499)] {
+            let range = visible_range(&heights, selected, 30);
+            assert!(range.len() <= 30 / 4 + 1, "{:?} rendered {} cards", selected, range.len());
+            if let Some(selected) = selected {
+                assert!(range.contains(&selected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_range_without_scrolling() {
+        let heights = vec![4, 4, 4];
+        assert_eq!(visible_range(&heights, Some(1), 30), 0..3);
+        assert_eq!(visible_range(&heights, None, 5), 0..2);
+        assert_eq!(visible_range(&[], None, 30), 0..0);
+    }
 
-    let list = List::new(items).block(block);
-    f.render_widget(list, area);
+    #[test]
+    fn test_visible_range_scrolls_to_selected() {
+        let heights = vec![4; 10];
+        // 12 lines fit three cards, so selecting the 6th card starts at the 4th
+        assert_eq!(visible_range(&heights, Some(5), 12), 3..6);
+    }
 }
@@ -0,0 +1,54 @@
+//! Command palette popup rendering for the Kanban TUI.
+
+use super::{centered_popup, theme};
+use crate::app::App;
+use crate::commands;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+pub fn render_command_palette(f: &mut Frame, app: &App, area: Rect) {
+    let matches = commands::matching(&app.input_buffer);
+    let rows = u16::try_from(matches.len().max(1)).unwrap_or(u16::MAX);
+    let popup_area = centered_popup(area, 60, rows.saturating_add(2));
+
+    let items: Vec<ListItem> = if matches.is_empty() {
+        vec![ListItem::new("No matching commands").style(Style::default().fg(Color::DarkGray))]
+    } else {
+        matches
+            .iter()
+            .map(|command| {
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{:<20}", command.name)),
+                    Span::styled(format!("{:<10}", command.keys), Style::default().fg(Color::Cyan)),
+                    Span::styled(command.description, Style::default().fg(Color::Gray)),
+                ]))
+            })
+            .collect()
+    };
+
+    // Without colors the highlight is invisible, so point at the selection instead
+    let highlight_symbol = if app.config.no_color { "> " } else { "" };
+    let list = List::new(items)
+        .highlight_symbol(highlight_symbol)
+        .block(
+            Block::default()
+                .title(format!(" :{} ", app.input_buffer))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(theme::selection_style(&app.config));
+
+    // The list state scrolls the highlighted command into view
+    let mut state = ListState::default();
+    if !matches.is_empty() {
+        state.select(Some(app.command_selection.min(matches.len() - 1)));
+    }
+
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
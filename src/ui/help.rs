@@ -0,0 +1,132 @@
+//! Help screen rendering for the Kanban TUI.
+
+use super::{centered_popup, theme};
+use crate::app::App;
+use crate::commands::COMMANDS;
+use kanban_tui::Priority;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Explain the card colors and markers, built from the theme so it can't drift
+fn legend_lines() -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        "Legend",
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+
+    for priority in Priority::all() {
+        let symbol = match priority.symbol() {
+            "" => "(none)".to_string(),
+            symbol => symbol.to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<18}", symbol),
+                Style::default().fg(theme::priority_color(priority)),
+            ),
+            Span::raw(format!("{} priority", priority)),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled(format!("{:<18}", "(overdue)"), Style::default().fg(theme::OVERDUE_COLOR)),
+        Span::raw("Due date has passed"),
+    ]));
+    lines
+}
+
+/// The board's name and description, when it has one
+fn board_header(app: &App) -> Option<Line<'_>> {
+    let description = app.board.description.as_str();
+    (!description.is_empty()).then(|| {
+        Line::from(vec![
+            Span::styled(app.current_board_name.as_str(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!(" — {}", description), Style::default().fg(Color::DarkGray)),
+        ])
+    })
+}
+
+pub fn render_help(f: &mut Frame, app: &App, area: Rect) {
+    let legend = legend_lines();
+    let header = board_header(app);
+    let header_height = if header.is_some() { 2 } else { 0 };
+    let popup_height = (COMMANDS.len() + legend.len()) as u16 + 7 + header_height;
+    let popup_area = centered_popup(area, 64, popup_height);
+
+    // The board and where its data lives come first so scrolling isn't needed to find them
+    let mut lines = Vec::new();
+    if let Some(header) = header {
+        lines.push(header);
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("Boards stored in: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(app.storage.boards_dir().display().to_string()),
+    ]));
+    lines.push(Line::from(""));
+
+    let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    lines.extend(COMMANDS.iter().map(|command| {
+        Line::from(vec![
+            Span::styled(format!("{:<18}", command.keys), key_style),
+            Span::raw(command.description),
+        ])
+    }));
+
+    lines.push(Line::from(""));
+    lines.extend(legend);
+
+    let scroll = help_scroll(app.help_scroll, lines.len(), popup_area.height.saturating_sub(2));
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Help (j/k to scroll) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Clamp the requested scroll so the last line stops at the bottom of the popup
+fn help_scroll(requested: u16, line_count: usize, visible_rows: u16) -> u16 {
+    let max = u16::try_from(line_count).unwrap_or(u16::MAX).saturating_sub(visible_rows);
+    requested.min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legend_lists_every_priority() {
+        let text: Vec<String> = legend_lines()
+            .iter()
+            .map(|line| line.spans.iter().map(|span| span.content.as_ref()).collect())
+            .collect();
+
+        for priority in Priority::all() {
+            let label = format!("{} priority", priority);
+            let line = text.iter().find(|line| line.contains(&label));
+            assert!(line.is_some(), "legend is missing {}", label);
+            assert!(line.unwrap().starts_with(priority.symbol()));
+        }
+        assert!(text.iter().any(|line| line.contains("(overdue)")));
+    }
+
+    #[test]
+    fn test_help_scroll_clamps_to_content() {
+        assert_eq!(help_scroll(5, 60, 20), 5);
+        assert_eq!(help_scroll(50, 60, 20), 40);
+        assert_eq!(help_scroll(5, 10, 20), 0);
+        assert_eq!(help_scroll(3, 10, 0), 3);
+    }
+}
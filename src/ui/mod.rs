@@ -2,63 +2,345 @@
 
 mod board_selector;
 mod column;
+mod command_palette;
+mod help;
 mod status_bar;
 mod task_detail;
+mod theme;
 
-use crate::app::{App, InputMode};
+use crate::app::{App, ConfirmAction, InputMode};
+use crate::config::Position;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
+use std::ops::Range;
 
 pub use board_selector::render_board_selector;
-pub use column::render_column;
+pub use column::{collapsed_column_width, render_collapsed_column, render_column};
+pub use command_palette::render_command_palette;
+pub use help::render_help;
 pub use status_bar::render_status_bar;
 pub use task_detail::render_task_detail;
 
+/// Smallest terminal size the board can be drawn in
+pub const MIN_WIDTH: u16 = 30;
+pub const MIN_HEIGHT: u16 = 10;
+
 /// Main UI rendering function
 pub fn ui(f: &mut Frame, app: &App) {
     let size = f.area();
 
-    // Create main layout: columns area + status bar
+    if is_too_small(size) {
+        render_too_small(f, size);
+        if app.config.no_color {
+            theme::strip_colors(f.buffer_mut());
+        }
+        return;
+    }
+
+    // Create main layout: columns area + status bar, in the configured order
+    let position = app.config.status_bar_position;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .constraints(main_constraints(position))
         .split(size);
+    let (columns_area, status_area) = match position {
+        Position::Top => (chunks[1], chunks[0]),
+        Position::Bottom => (chunks[0], chunks[1]),
+    };
 
     // Render columns
-    render_columns(f, app, chunks[0]);
+    render_columns(f, app, columns_area);
 
     // Render status bar
-    render_status_bar(f, app, chunks[1]);
+    render_status_bar(f, app, status_area);
 
     // Render task detail popup if in viewing mode
     if app.input_mode == InputMode::Viewing {
         render_task_detail(f, app, size);
     }
 
-    // Render board selector if in board selection mode
-    if app.input_mode == InputMode::SelectingBoard {
+    if app.input_mode == InputMode::Help {
+        render_help(f, app, size);
+    }
+
+    if app.input_mode == InputMode::CommandPalette {
+        render_command_palette(f, app, size);
+    }
+
+    // Render board selector if in board selection mode (or confirming a board deletion)
+    let confirming_board_delete = app.input_mode == InputMode::Confirming
+        && matches!(app.pending_confirmation, Some(ConfirmAction::DeleteBoard(_)));
+    if app.input_mode == InputMode::SelectingBoard || confirming_board_delete {
         render_board_selector(f, app, size);
     }
+
+    if app.config.no_color {
+        theme::strip_colors(f.buffer_mut());
+    }
+}
+
+/// Vertical constraints for the columns area and the status bar, top to bottom
+fn main_constraints(status_bar: Position) -> [Constraint; 2] {
+    let columns = Constraint::Min(0);
+    let status = Constraint::Length(3);
+    match status_bar {
+        Position::Top => [status, columns],
+        Position::Bottom => [columns, status],
+    }
 }
 
 fn render_columns(f: &mut Frame, app: &App, area: Rect) {
     let column_count = app.board.columns.len();
-    let constraints = vec![Constraint::Percentage(100 / column_count as u16); column_count];
-
+    if column_count == 0 {
+        return;
+    }
+    let visible = drawn_columns(app, area.width);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(constraints)
+        .constraints(column_constraints(app, visible.clone()))
         .split(area);
 
-    for (i, column) in app.board.columns.iter().enumerate() {
-        let is_selected_column = i == app.selected_column;
-        let selected_task = if is_selected_column {
-            app.selected_task_index
+    for (i, chunk) in visible.zip(chunks.iter()) {
+        if is_drawn_collapsed(app, i) {
+            render_collapsed_column(f, app, i, *chunk);
         } else {
-            None
-        };
-        render_column(f, column, is_selected_column, selected_task, chunks[i]);
+            render_column(f, app, i, *chunk);
+        }
+    }
+
+    // A board with no tasks at all gets a hint on how to add the first one
+    if app.board.is_empty() {
+        render_empty_hint(f, area);
+    }
+}
+
+fn render_empty_hint(f: &mut Frame, area: Rect) {
+    let popup_area = centered_popup(area, 36, 3);
+    f.render_widget(Clear, popup_area);
+    let hint = Paragraph::new("Press n to create your first task")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+    f.render_widget(hint, popup_area);
+}
+
+/// Collapsed columns are drawn as just a header unless they're selected
+fn is_drawn_collapsed(app: &App, column_index: usize) -> bool {
+    app.board.columns[column_index].collapsed && column_index != app.selected_column
+}
+
+/// Columns to draw: just the selected one in focus mode, otherwise as many as fit
+fn drawn_columns(app: &App, width: u16) -> Range<usize> {
+    if app.board.focus_mode {
+        let selected = app.selected_column.min(app.board.columns.len() - 1);
+        return selected..selected + 1;
+    }
+    visible_columns(
+        width,
+        app.board.columns.len(),
+        app.config.min_column_width,
+        app.selected_column,
+    )
+}
+
+/// Compute which columns to draw so the selected one stays in view.
+///
+/// When every column can't get `min_width` cells, only as many as fit are
+/// drawn, scrolled just far enough to include the selected column.
+fn visible_columns(width: u16, column_count: usize, min_width: u16, selected: usize) -> Range<usize> {
+    let fit = match min_width {
+        0 => column_count,
+        min_width => usize::from(width / min_width).max(1),
+    };
+    if column_count <= fit {
+        return 0..column_count;
+    }
+
+    let start = selected.min(column_count - 1).saturating_sub(fit - 1);
+    start..start + fit
+}
+
+/// Width constraints for each drawn column: collapsed ones fit their header
+/// and expanded ones share the remaining space equally
+fn column_constraints(app: &App, columns: Range<usize>) -> Vec<Constraint> {
+    columns
+        .map(|i| {
+            if is_drawn_collapsed(app, i) {
+                Constraint::Length(collapsed_column_width(&app.board.columns[i]))
+            } else {
+                Constraint::Fill(1)
+            }
+        })
+        .collect()
+}
+
+/// Returns true if the terminal is too small to draw the board sensibly
+fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+fn render_too_small(f: &mut Frame, area: Rect) {
+    let message = Paragraph::new("Terminal too small")
+        .style(Style::default().fg(Color::Yellow))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(message, area);
+}
+
+/// Compute a popup of at most `width` x `height` centered in `area`.
+///
+/// The popup keeps a two-cell margin on every side and always fits inside
+/// `area`, shrinking to nothing when there is no room.
+pub(crate) fn centered_popup(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width.saturating_sub(4));
+    let height = height.min(area.height.saturating_sub(4));
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use kanban_tui::storage::Storage;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_ui_renders_at_tiny_sizes() {
+        let dir = std::env::temp_dir().join(format!("kanban-ui-test-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        app.board.add_task(0, "Task").unwrap();
+        app.selected_task_index = Some(0);
+
+        for mode in [InputMode::Normal, InputMode::Viewing, InputMode::SelectingBoard,
+            InputMode::Help, InputMode::CommandPalette] {
+            app.input_mode = mode;
+            for width in 0..40 {
+                for height in [0, 5, 12] {
+                    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                    terminal.draw(|f| ui(f, &app)).unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_board_selector_shows_every_hint() {
+        let dir = std::env::temp_dir().join(format!("kanban-ui-selector-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        app.input_mode = InputMode::SelectingBoard;
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|f| ui(f, &app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        for hint in ["Enter: switch", "a/A: archive/show archived", "c: copy columns here", "Esc: cancel"] {
+            assert!(text.contains(hint), "missing hint '{}'", hint);
+        }
+    }
+
+    #[test]
+    fn test_collapsed_columns_only_take_header_width() {
+        let dir = std::env::temp_dir().join(format!("kanban-ui-collapse-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        app.board.columns[2].collapsed = true;
+
+        let constraints = column_constraints(&app, 0..3);
+        assert_eq!(constraints[0], Constraint::Fill(1));
+        assert_eq!(constraints[1], Constraint::Fill(1));
+        assert_eq!(constraints[2], Constraint::Length(collapsed_column_width(&app.board.columns[2])));
+
+        // The selected column is always drawn expanded
+        app.selected_column = 2;
+        assert_eq!(column_constraints(&app, 0..3)[2], Constraint::Fill(1));
+    }
+
+    #[test]
+    fn test_focus_mode_draws_only_the_selected_column() {
+        let dir = std::env::temp_dir().join(format!("kanban-ui-focus-{}", std::process::id()));
+        let mut app = App::with_storage(Storage::with_path(dir), Config::default());
+        app.selected_column = 1;
+        assert_eq!(drawn_columns(&app, 120), 0..3);
+
+        app.board.focus_mode = true;
+        assert_eq!(drawn_columns(&app, 120), 1..2);
+        assert_eq!(column_constraints(&app, 1..2), vec![Constraint::Fill(1)]);
+
+        app.next_column();
+        assert_eq!(drawn_columns(&app, 120), 2..3);
+    }
+
+    #[test]
+    fn test_visible_columns_fit_without_scrolling() {
+        assert_eq!(visible_columns(120, 3, 20, 0), 0..3);
+        assert_eq!(visible_columns(60, 3, 20, 2), 0..3);
+        // A minimum width of zero always fits everything
+        assert_eq!(visible_columns(10, 8, 0, 7), 0..8);
+    }
+
+    #[test]
+    fn test_visible_columns_scroll_to_selection() {
+        // 100 cells at 20 per column fit 5 of 8 columns
+        assert_eq!(visible_columns(100, 8, 20, 0), 0..5);
+        assert_eq!(visible_columns(100, 8, 20, 4), 0..5);
+        assert_eq!(visible_columns(100, 8, 20, 5), 1..6);
+        assert_eq!(visible_columns(100, 8, 20, 7), 3..8);
+        // Out of range selections clamp to the last column
+        assert_eq!(visible_columns(100, 8, 20, 42), 3..8);
+    }
+
+    #[test]
+    fn test_visible_columns_always_show_one() {
+        assert_eq!(visible_columns(10, 8, 20, 6), 6..7);
+        assert_eq!(visible_columns(0, 8, 20, 0), 0..1);
+    }
+
+    #[test]
+    fn test_main_constraints_follow_status_bar_position() {
+        assert_eq!(
+            main_constraints(Position::Bottom),
+            [Constraint::Min(0), Constraint::Length(3)]
+        );
+        assert_eq!(
+            main_constraints(Position::Top),
+            [Constraint::Length(3), Constraint::Min(0)]
+        );
+    }
+
+    #[test]
+    fn test_centered_popup_fits_tiny_areas() {
+        for size in 0..=10 {
+            let area = Rect::new(0, 0, size, size);
+            let popup = centered_popup(area, 60, 20);
+            assert_eq!(popup.width, size.saturating_sub(4));
+            assert_eq!(popup.height, size.saturating_sub(4));
+            assert_eq!(popup.intersection(area), popup, "popup escapes a {0}x{0} area", size);
+        }
+    }
+
+    #[test]
+    fn test_centered_popup_is_centered() {
+        let area = Rect::new(5, 2, 100, 40);
+        assert_eq!(centered_popup(area, 60, 20), Rect::new(25, 12, 60, 20));
+    }
+
+    #[test]
+    fn test_too_small_threshold() {
+        assert!(is_too_small(Rect::new(0, 0, 0, 0)));
+        assert!(is_too_small(Rect::new(0, 0, MIN_WIDTH - 1, MIN_HEIGHT)));
+        assert!(!is_too_small(Rect::new(0, 0, MIN_WIDTH, MIN_HEIGHT)));
     }
 }
@@ -1,8 +1,9 @@
 //! Status bar rendering for the Kanban TUI.
 
-use crate::app::{App, InputMode};
+use super::task_detail::detail_popup_area;
+use crate::app::{App, InputMode, NotificationLevel};
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -16,11 +17,30 @@ pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
             build_input_prompt("Creating task: ", &app.input_buffer),
             Style::default().fg(Color::Yellow),
         ),
+        InputMode::Capturing => (
+            build_input_prompt("Capture: ", &app.input_buffer),
+            Style::default().fg(Color::Yellow),
+        ),
         InputMode::Editing => (
             build_input_prompt("Editing title: ", &app.input_buffer),
             Style::default().fg(Color::Green),
         ),
+        InputMode::Viewing if detail_popup_area(f.area()).is_none() => (
+            Line::from("Terminal too small — resize to view details (Esc to close)"),
+            Style::default().fg(Color::Yellow),
+        ),
         InputMode::Viewing => (build_viewing_help(), Style::default().fg(Color::Cyan)),
+        InputMode::Help => (
+            Line::from(vec![
+                Span::styled("Help", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" | "),
+                Span::styled("j/k PgUp/PgDn", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": scroll | Press "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to close"),
+            ]),
+            Style::default().fg(Color::Cyan),
+        ),
         InputMode::EditingDescription => (
             build_input_prompt("Editing description: ", &app.input_buffer),
             Style::default().fg(Color::Magenta),
@@ -34,22 +54,171 @@ pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
             build_input_prompt("New board name: ", &app.input_buffer),
             Style::default().fg(Color::Cyan),
         ),
+        InputMode::Confirming => (build_confirmation_prompt(app), Style::default().fg(Color::Red)),
+        InputMode::TaggingColumn => (
+            build_input_prompt("Tag all in column (-tag to remove): ", &app.input_buffer),
+            Style::default().fg(Color::Blue),
+        ),
+        InputMode::CompletingTag => (
+            build_input_prompt("Move to done every task tagged: ", &app.input_buffer),
+            Style::default().fg(Color::Blue),
+        ),
+        InputMode::SettingDueDate => (
+            build_input_prompt("Due (YYYY-MM-DD [HH:MM], empty clears): ", &app.input_buffer),
+            Style::default().fg(Color::Magenta),
+        ),
+        InputMode::SettingStartDate => (
+            build_input_prompt("Start (YYYY-MM-DD [HH:MM], empty clears): ", &app.input_buffer),
+            Style::default().fg(Color::Magenta),
+        ),
+        InputMode::SettingFilteredPriority => (
+            build_input_prompt(
+                format!("Priority for {} filtered task(s) (high/medium/low/none): ", app.filtered_task_ids().len()),
+                &app.input_buffer,
+            ),
+            Style::default().fg(Color::Magenta),
+        ),
+        InputMode::EditingBoardDescription => (
+            build_input_prompt("Board description (empty clears): ", &app.input_buffer),
+            Style::default().fg(Color::Cyan),
+        ),
+        InputMode::CommandPalette => (
+            build_input_prompt("Command (↑/↓ select, Enter runs): ", &app.input_buffer),
+            Style::default().fg(Color::Cyan),
+        ),
+        InputMode::Filtering => (
+            build_input_prompt("Filter (#tag !priority is:overdue): ", &app.input_buffer),
+            Style::default().fg(Color::Yellow),
+        ),
     };
 
+    let mut block = Block::default().borders(Borders::ALL).style(style);
+
+    // Normal mode shows status messages in the help line; input modes show them on the border
+    if app.input_mode != InputMode::Normal {
+        if let Some(message) = &app.status_message {
+            block = block.title(Span::styled(
+                format!(" {} ", message),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+
+    // The newest notification is shown as a toast on the right of the border
+    if let Some(notification) = app.current_notification() {
+        let color = match notification.level {
+            NotificationLevel::Info => Color::Green,
+            NotificationLevel::Error => Color::Red,
+        };
+        block = block.title_top(
+            Line::from(Span::styled(
+                format!(" {} ", notification.text),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ))
+            .right_aligned(),
+        );
+    }
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Completion and save indicators are right-aligned next to the normal mode help
+    let indicator = if app.input_mode == InputMode::Normal {
+        Some(build_right_indicator(app))
+    } else {
+        None
+    };
+    let indicator_width = indicator.as_ref().map_or(0, |line| line.width() as u16);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(indicator_width)])
+        .split(inner);
+
     let paragraph = Paragraph::new(text)
         .style(style)
-        .block(Block::default().borders(Borders::ALL))
         .alignment(Alignment::Left);
+    f.render_widget(paragraph, chunks[0]);
 
-    f.render_widget(paragraph, area);
+    if let Some(indicator) = indicator {
+        f.render_widget(Paragraph::new(indicator).alignment(Alignment::Right), chunks[1]);
+    }
 }
 
-fn build_normal_mode_help(app: &App) -> Line<'_> {
-    Line::from(vec![
-        Span::styled(
-            format!("[{}] ", app.current_board_name),
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+/// Width of the completion bar in characters (excluding brackets)
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// Build a text progress bar such as `[█████░░░░░] 50%`
+fn progress_bar(ratio: f64, width: usize) -> String {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let filled = (ratio * width as f64).round() as usize;
+    format!(
+        "[{}{}] {:.0}%",
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        ratio * 100.0
+    )
+}
+
+fn build_right_indicator(app: &App) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        progress_bar(app.board.completion_ratio(), PROGRESS_BAR_WIDTH),
+        Style::default().fg(Color::Green),
+    )];
+
+    if let Some(save_indicator) = build_save_indicator(app) {
+        spans.push(save_indicator);
+    }
+
+    Line::from(spans)
+}
+
+fn build_save_indicator(app: &App) -> Option<Span<'static>> {
+    let (saved_at, result) = app.last_save.as_ref()?;
+
+    let span = match result {
+        Ok(()) => {
+            let elapsed = chrono::Duration::from_std(saved_at.elapsed()).unwrap_or_default();
+            let wall_time = chrono::Local::now() - elapsed;
+            Span::styled(
+                format!(" saved {}", wall_time.format("%H:%M:%S")),
+                Style::default().fg(Color::DarkGray),
+            )
+        }
+        Err(_) => Span::styled(
+            " save failed",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         ),
+    };
+
+    Some(span)
+}
+
+fn build_normal_mode_help(app: &App) -> Line<'_> {
+    // Unsaved changes are marked with a `*` after the board name
+    let dirty_marker = if app.dirty { "*" } else { "" };
+    let board_label = Span::styled(
+        format!("[{}{}] ", app.current_board_name, dirty_marker),
+        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+    );
+
+    if let Some(message) = &app.status_message {
+        return Line::from(vec![
+            board_label,
+            Span::styled(message.as_str(), Style::default().fg(Color::Yellow)),
+        ]);
+    }
+
+    let mut spans = vec![board_label];
+    if app.filter.is_active() {
+        spans.push(Span::styled(
+            format!("filter: {} ", app.filter),
+            Style::default().fg(Color::Yellow),
+        ));
+        spans.push(Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)));
+        spans.push(Span::raw(": clear | "));
+    }
+
+    spans.extend([
         Span::styled("b", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(": boards | "),
         Span::styled("n", Style::default().add_modifier(Modifier::BOLD)),
@@ -60,9 +229,20 @@ fn build_normal_mode_help(app: &App) -> Line<'_> {
         Span::raw(": priority | "),
         Span::styled("d", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(": delete | "),
+        Span::styled("a", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": archive task | "),
+        Span::styled("u", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": undo | "),
+        Span::styled("/", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": filter | "),
+        Span::styled("w", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": write | "),
+        Span::styled("?", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": help | "),
         Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(": quit"),
-    ])
+    ]);
+    Line::from(spans)
 }
 
 fn build_board_selector_help() -> Line<'static> {
@@ -83,7 +263,20 @@ fn build_board_selector_help() -> Line<'static> {
     ])
 }
 
-fn build_input_prompt<'a>(label: &'a str, buffer: &'a str) -> Line<'a> {
+fn build_confirmation_prompt(app: &App) -> Line<'static> {
+    let prompt = app
+        .pending_confirmation
+        .as_ref()
+        .map(|action| action.prompt())
+        .unwrap_or_default();
+
+    Line::from(vec![Span::styled(
+        prompt,
+        Style::default().add_modifier(Modifier::BOLD),
+    )])
+}
+
+fn build_input_prompt<'a>(label: impl Into<std::borrow::Cow<'a, str>>, buffer: &'a str) -> Line<'a> {
     Line::from(vec![
         Span::styled(label, Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(buffer),
@@ -102,8 +295,30 @@ fn build_viewing_help() -> Line<'static> {
             "Viewing task details",
             Style::default().add_modifier(Modifier::BOLD),
         ),
-        Span::raw(" | Press "),
+        Span::raw(" | "),
+        Span::styled("x", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": clear description | "),
+        Span::styled("X", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": clear due date | "),
         Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" to close"),
+        Span::raw(": close"),
     ])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(progress_bar(0.0, 4), "[░░░░] 0%");
+        assert_eq!(progress_bar(0.5, 4), "[██░░] 50%");
+        assert_eq!(progress_bar(1.0, 4), "[████] 100%");
+    }
+
+    #[test]
+    fn test_progress_bar_clamps_ratio() {
+        assert_eq!(progress_bar(1.5, 2), "[██] 100%");
+        assert_eq!(progress_bar(-1.0, 2), "[░░] 0%");
+    }
+}
@@ -1,7 +1,7 @@
 //! Task detail popup rendering for the Kanban TUI.
 
+use super::{centered_popup, theme};
 use crate::app::App;
-use kanban_tui::Priority;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -10,115 +10,224 @@ use ratatui::{
     Frame,
 };
 
+/// Smallest popup worth drawing: the borders plus the title, description and priority
+const MIN_POPUP_WIDTH: u16 = 24;
+const MIN_POPUP_HEIGHT: u16 = 8;
+
+/// Where the detail popup goes in `area`, or `None` if even the smallest
+/// useful popup doesn't fit (the status bar then asks for a bigger terminal)
+pub(crate) fn detail_popup_area(area: Rect) -> Option<Rect> {
+    let popup_area = centered_popup(area, 60, 20);
+    (popup_area.width >= MIN_POPUP_WIDTH && popup_area.height >= MIN_POPUP_HEIGHT).then_some(popup_area)
+}
+
 pub fn render_task_detail(f: &mut Frame, app: &App, area: Rect) {
-    if let Some(task_idx) = app.selected_task_index {
-        let column = &app.board.columns[app.selected_column];
-        if task_idx < column.tasks.len() {
-            let task = &column.tasks[task_idx];
-
-            // Create centered popup area
-            let popup_width = 60.min(area.width - 4);
-            let popup_height = 20.min(area.height - 4);
-            let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-            let popup_y = (area.height.saturating_sub(popup_height)) / 2;
-
-            let popup_area = Rect {
-                x: area.x + popup_x,
-                y: area.y + popup_y,
-                width: popup_width,
-                height: popup_height,
-            };
-
-            // Build content lines
-            let mut lines = vec![
-                Line::from(vec![
-                    Span::styled("Title: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(&task.title),
-                ]),
-                Line::from(""),
-            ];
-
-            // Description
-            if let Some(desc) = &task.description {
-                if !desc.is_empty() {
-                    lines.push(Line::from(vec![Span::styled(
-                        "Description: ",
-                        Style::default().add_modifier(Modifier::BOLD),
-                    )]));
-                    lines.push(Line::from(desc.as_str()));
-                    lines.push(Line::from(""));
-                }
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("Description: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("(none)", Style::default().fg(Color::Gray)),
-                ]));
-                lines.push(Line::from(""));
-            }
+    let Some(task) = app.selected_task() else {
+        return;
+    };
+    let Some(popup_area) = detail_popup_area(area) else {
+        return;
+    };
 
-            // Priority with color coding
-            let priority_color = match task.priority {
-                Priority::High => Color::Red,
-                Priority::Medium => Color::Yellow,
-                Priority::Low => Color::Green,
-                Priority::None => Color::Gray,
-            };
-            lines.push(Line::from(vec![
-                Span::styled("Priority: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(
-                    format!("{} {}", task.priority.symbol(), task.priority),
-                    Style::default()
-                        .fg(priority_color)
-                        .add_modifier(Modifier::BOLD),
-                ),
-            ]));
-            lines.push(Line::from(""));
+    // Build content lines
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Title: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(&task.title),
+        ]),
+        Line::from(""),
+    ];
 
-            // Tags with color coding
-            if !task.tags.is_empty() {
-                lines.push(Line::from(vec![
-                    Span::styled("Tags: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled(task.tags.join(", "), Style::default().fg(Color::Cyan)),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("Tags: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("(none)", Style::default().fg(Color::Gray)),
-                ]));
-            }
+    // Description
+    if let Some(desc) = &task.description {
+        if !desc.is_empty() {
+            lines.push(Line::from(vec![Span::styled(
+                "Description: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            lines.push(Line::from(desc.as_str()));
             lines.push(Line::from(""));
+        }
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled("Description: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("(none)", Style::default().fg(Color::Gray)),
+        ]));
+        lines.push(Line::from(""));
+    }
 
-            // Timestamps
-            lines.push(Line::from(vec![
-                Span::styled("Created: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(&task.created_at),
-            ]));
-            lines.push(Line::from(vec![
-                Span::styled("Updated: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(&task.updated_at),
-            ]));
-
-            // Due date
-            if let Some(due) = &task.due_date {
-                lines.push(Line::from(""));
-                lines.push(Line::from(vec![
-                    Span::styled("Due Date: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(due),
-                ]));
+    // Priority with color coding
+    let priority_color = theme::priority_color(task.priority);
+    lines.push(Line::from(vec![
+        Span::styled("Priority: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!("{} {}", task.priority.symbol(), app.board.priority_label(task)),
+            Style::default()
+                .fg(priority_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    // Tags with color coding
+    if !task.tags.is_empty() {
+        let mut spans = vec![Span::styled(
+            "Tags: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        for (tag_idx, tag) in task.tags.iter().enumerate() {
+            if tag_idx > 0 {
+                spans.push(Span::raw(", "));
             }
+            spans.push(Span::styled(
+                tag.as_str(),
+                Style::default().fg(theme::tag_color(&app.config, tag)),
+            ));
+        }
+        lines.push(Line::from(spans));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled("Tags: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("(none)", Style::default().fg(Color::Gray)),
+        ]));
+    }
+
+    // Watchers
+    if !task.watchers.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Watchers: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(task.watchers.join(", ")),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    // Timestamps, with how long ago they were when they can be parsed
+    lines.push(Line::from(vec![
+        Span::styled("Created: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(&task.created_at),
+        Span::styled(relative(task.age()), Style::default().fg(Color::Gray)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Updated: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(&task.updated_at),
+        Span::styled(relative(task.since_update()), Style::default().fg(Color::Gray)),
+    ]));
+
+    if let Some(duration) = task.time_in_current_column() {
+        lines.push(Line::from(vec![
+            Span::styled("In column: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format_duration(duration)),
+        ]));
+    }
+
+    // Due date
+    if let Some(due) = &task.due_date {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Due Date: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(due),
+        ]));
+    }
+
+    // Start date
+    if let Some(start) = &task.start_date {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Start Date: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(start),
+        ]));
+    }
 
-            // Clear the area and render popup
-            f.render_widget(Clear, popup_area);
-            let paragraph = Paragraph::new(lines)
-                .block(
-                    Block::default()
-                        .title(" Task Details (press Esc to close) ")
-                        .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Cyan)),
-                )
-                .wrap(Wrap { trim: true });
-
-            f.render_widget(paragraph, popup_area);
+    // Clear the area and render popup
+    f.render_widget(Clear, popup_area);
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Task Details (press Esc to close) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Suffix for a timestamp, e.g. " (2 hours ago)", or nothing if it couldn't be parsed
+fn relative(elapsed: Option<chrono::Duration>) -> String {
+    elapsed
+        .map(|elapsed| format!(" ({})", humanize_ago(elapsed)))
+        .unwrap_or_default()
+}
+
+/// Describe how long ago something happened by its largest unit, e.g. "3 days ago"
+fn humanize_ago(elapsed: chrono::Duration) -> String {
+    let seconds = elapsed.num_seconds().max(0);
+    let (count, unit) = match seconds {
+        0 => return "just now".to_string(),
+        1..=59 => (seconds, "second"),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86_399 => (seconds / 3600, "hour"),
+        _ => (seconds / 86_400, "day"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{} {}{} ago", count, unit, plural)
+}
+
+/// Format a duration coarsely, e.g. "3d 4h", "2h 15m" or "5m"
+fn format_duration(duration: chrono::Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::minutes(5)), "5m");
+        assert_eq!(format_duration(Duration::minutes(135)), "2h 15m");
+        assert_eq!(format_duration(Duration::hours(76)), "3d 4h");
+        assert_eq!(format_duration(Duration::minutes(-3)), "0m");
+    }
+
+    #[test]
+    fn test_detail_popup_area_at_small_sizes() {
+        assert_eq!(detail_popup_area(Rect::new(0, 0, 100, 40)), Some(Rect::new(20, 10, 60, 20)));
+        assert_eq!(detail_popup_area(Rect::new(0, 0, 40, 14)), Some(Rect::new(2, 2, 36, 10)));
+        assert_eq!(detail_popup_area(Rect::new(0, 0, 80, 12)).map(|r| r.height), Some(MIN_POPUP_HEIGHT));
+
+        assert_eq!(detail_popup_area(Rect::new(0, 0, 80, 11)), None);
+        assert_eq!(detail_popup_area(Rect::new(0, 0, 27, 30)), None);
+        for height in 0..4 {
+            assert_eq!(detail_popup_area(Rect::new(0, 0, 80, height)), None);
         }
     }
+
+    #[test]
+    fn test_humanize_ago() {
+        assert_eq!(humanize_ago(Duration::zero()), "just now");
+        assert_eq!(humanize_ago(Duration::seconds(1)), "1 second ago");
+        assert_eq!(humanize_ago(Duration::seconds(45)), "45 seconds ago");
+        assert_eq!(humanize_ago(Duration::seconds(90)), "1 minute ago");
+        assert_eq!(humanize_ago(Duration::minutes(59)), "59 minutes ago");
+        assert_eq!(humanize_ago(Duration::minutes(125)), "2 hours ago");
+        assert_eq!(humanize_ago(Duration::hours(49)), "2 days ago");
+        assert_eq!(humanize_ago(Duration::seconds(-30)), "just now");
+    }
+
+    #[test]
+    fn test_relative_is_omitted_without_a_time() {
+        assert_eq!(relative(Some(Duration::hours(2))), " (2 hours ago)");
+        assert_eq!(relative(None), "");
+    }
 }
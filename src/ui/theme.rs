@@ -0,0 +1,147 @@
+//! Color helpers shared by the UI rendering modules.
+
+use crate::config::Config;
+use kanban_tui::Priority;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier, Style};
+
+/// Color used for tags without a configured color
+pub const DEFAULT_TAG_COLOR: Color = Color::Cyan;
+
+/// Color used for overdue due dates
+pub const OVERDUE_COLOR: Color = Color::Red;
+
+/// Color used for a task card of the given priority
+pub fn priority_color(priority: Priority) -> Color {
+    match priority {
+        Priority::High => Color::Red,
+        Priority::Medium => Color::Yellow,
+        Priority::Low => Color::Green,
+        Priority::None => Color::White,
+    }
+}
+
+/// Text marker standing in for a priority's color when colors are off
+pub fn priority_marker(priority: Priority) -> &'static str {
+    match priority {
+        Priority::High => "[HIGH]",
+        Priority::Medium => "[MEDIUM]",
+        Priority::Low => "[LOW]",
+        Priority::None => "",
+    }
+}
+
+/// Reset every cell to the terminal's default colors, keeping bold and other modifiers.
+///
+/// Applied to the whole frame when colors are off, so individual widgets can
+/// keep their styles and only need to add text markers for state.
+pub fn strip_colors(buffer: &mut Buffer) {
+    for cell in &mut buffer.content {
+        cell.set_fg(Color::Reset);
+        cell.set_bg(Color::Reset);
+    }
+}
+
+/// Parse a color name (e.g. "red", "light blue") or hex value (e.g. "#ff8800")
+pub fn parse_color(value: &str) -> Option<Color> {
+    value.trim().parse().ok()
+}
+
+/// Style for the selected task, board or command.
+///
+/// Unreadable color names are ignored, leaving that part of the style unset.
+pub fn selection_style(config: &Config) -> Style {
+    let selection = &config.selection_style;
+    let mut style = Style::default();
+    if let Some(bg) = selection.bg.as_deref().and_then(parse_color) {
+        style = style.bg(bg);
+    }
+    if let Some(fg) = selection.fg.as_deref().and_then(parse_color) {
+        style = style.fg(fg);
+    }
+    for (enabled, modifier) in [
+        (selection.bold, Modifier::BOLD),
+        (selection.underline, Modifier::UNDERLINED),
+        (selection.reverse, Modifier::REVERSED),
+    ] {
+        if enabled {
+            style = style.add_modifier(modifier);
+        }
+    }
+    style
+}
+
+/// Resolve the display color for a tag, falling back to cyan for unmapped tags
+pub fn tag_color(config: &Config, tag: &str) -> Color {
+    config
+        .tag_colors
+        .get(tag)
+        .and_then(|color| parse_color(color))
+        .unwrap_or(DEFAULT_TAG_COLOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SelectionStyle;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("red"), Some(Color::Red));
+        assert_eq!(parse_color(" Light Blue "), Some(Color::LightBlue));
+        assert_eq!(parse_color("#ff0000"), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(parse_color("not-a-color"), None);
+    }
+
+    #[test]
+    fn test_strip_colors_keeps_modifiers() {
+        use ratatui::layout::Rect;
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "High", Style::default().fg(Color::Red).bg(Color::Cyan).add_modifier(Modifier::BOLD));
+        strip_colors(&mut buffer);
+
+        let cell = &buffer.content[0];
+        assert_eq!(cell.fg, Color::Reset);
+        assert_eq!(cell.bg, Color::Reset);
+        assert!(cell.modifier.contains(Modifier::BOLD));
+        assert_eq!(cell.symbol(), "H");
+    }
+
+    #[test]
+    fn test_selection_style() {
+        let mut config = Config::default();
+        assert_eq!(
+            selection_style(&config),
+            Style::default().bg(Color::Cyan).fg(Color::Black).add_modifier(Modifier::BOLD)
+        );
+
+        config.selection_style = SelectionStyle {
+            bg: None,
+            fg: Some("#ffff00".to_string()),
+            bold: false,
+            underline: true,
+            reverse: true,
+        };
+        assert_eq!(
+            selection_style(&config),
+            Style::default()
+                .fg(Color::Rgb(255, 255, 0))
+                .add_modifier(Modifier::UNDERLINED | Modifier::REVERSED)
+        );
+
+        config.selection_style.fg = Some("not-a-color".to_string());
+        assert_eq!(selection_style(&config).fg, None);
+    }
+
+    #[test]
+    fn test_tag_color_mapping() {
+        let mut config = Config::default();
+        config.tag_colors.insert("bug".to_string(), "red".to_string());
+        config.tag_colors.insert("broken".to_string(), "nonsense".to_string());
+
+        assert_eq!(tag_color(&config, "bug"), Color::Red);
+        assert_eq!(tag_color(&config, "feature"), DEFAULT_TAG_COLOR);
+        assert_eq!(tag_color(&config, "broken"), DEFAULT_TAG_COLOR);
+    }
+}
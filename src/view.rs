@@ -0,0 +1,50 @@
+//! Serializable read model of a board for frontends.
+//!
+//! A [`BoardView`] is a flattened snapshot: tasks carry their column name and
+//! computed fields such as whether they're overdue, and storage internals like
+//! the next task id are left out. Its shape is independent of the on-disk
+//! format, so it can be handed to web or other frontends as-is.
+
+use serde::Serialize;
+
+/// Snapshot of a board produced by [`Board::to_view`](crate::Board::to_view)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BoardView {
+    pub name: String,
+    pub description: String,
+    pub columns: Vec<ColumnView>,
+    /// Every task on the board, in column order
+    pub tasks: Vec<TaskView>,
+    /// Fraction of tasks in the done column (0.0 for an empty board)
+    pub completion_ratio: f64,
+}
+
+/// A column's name and load, without its tasks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnView {
+    pub name: String,
+    pub task_count: usize,
+    pub wip_limit: Option<usize>,
+    pub is_done: bool,
+}
+
+/// A task flattened together with its column and computed fields
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskView {
+    pub id: usize,
+    pub title: String,
+    pub description: Option<String>,
+    /// Display name of the priority, honoring a custom priority scheme
+    pub priority: String,
+    pub tags: Vec<String>,
+    pub watchers: Vec<String>,
+    pub due_date: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub column: String,
+    pub column_index: usize,
+    /// Past its due date and not in the done column
+    pub overdue: bool,
+    /// Seconds since creation, if the creation time is readable
+    pub age_seconds: Option<i64>,
+}
@@ -48,7 +48,7 @@ fn test_custom_board() {
         "Done".to_string(),
     ];
 
-    let mut board = Board::with_columns("Custom Board".to_string(), column_names);
+    let mut board = Board::with_columns("Custom Board".to_string(), column_names).unwrap();
 
     assert_eq!(board.columns.len(), 5);
     assert_eq!(board.columns[0].name, "Backlog");